@@ -0,0 +1,54 @@
+//! Generates the registry of the problem solvers linked into the `euler` runner.
+
+#![warn(
+    bad_style,
+    unused,
+    unused_extern_crates,
+    unused_import_braces,
+    unused_qualifications,
+    unused_results
+)]
+
+use std::{
+    env,
+    fs::{self, File},
+    io::prelude::*,
+    path::PathBuf,
+};
+
+const PROBLEM_DIR: &str = "src/bin";
+
+fn is_problem(name: &str) -> bool {
+    let bytes = name.as_bytes();
+    bytes.len() == 7
+        && bytes[0] == b'p'
+        && bytes[1..4].iter().all(u8::is_ascii_digit)
+        && name.ends_with(".rs")
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed={}", PROBLEM_DIR);
+
+    let manifest_dir = PathBuf::from(env::var_os("CARGO_MANIFEST_DIR").unwrap());
+    let mut names = fs::read_dir(manifest_dir.join(PROBLEM_DIR))
+        .unwrap()
+        .filter_map(|entry| entry.unwrap().file_name().into_string().ok())
+        .filter(|name| is_problem(name))
+        .map(|name| name.trim_end_matches(".rs").to_string())
+        .collect::<Vec<_>>();
+    names.sort();
+
+    let out_path = PathBuf::from(env::var_os("OUT_DIR").unwrap()).join("registry.rs");
+    let mut out = File::create(out_path).unwrap();
+    for name in &names {
+        let path = manifest_dir.join(PROBLEM_DIR).join(format!("{}.rs", name));
+        writeln!(out, "#[path = {:?}]", path.to_str().unwrap()).unwrap();
+        writeln!(out, "mod {};", name).unwrap();
+    }
+    writeln!(out, "pub const PROBLEMS: &[Entry] = &[").unwrap();
+    for name in &names {
+        writeln!(out, "    ({:?}, {}::solver),", name, name).unwrap();
+    }
+    writeln!(out, "];").unwrap();
+}
@@ -16,7 +16,8 @@ extern crate test;
 
 use bit_set::BitSet;
 use std::{
-    cmp::Ordering,
+    cmp::{Ordering, Reverse},
+    collections::BinaryHeap,
     iter::{self, Peekable},
 };
 
@@ -311,6 +312,307 @@ where
     }
 }
 
+/// An iterator that enumerates elements contained in either of the two sorted iterators.
+pub struct Union<A, B>
+where
+    A: Iterator,
+    B: Iterator,
+{
+    a: Peekable<A>,
+    b: Peekable<B>,
+}
+
+impl<E, A, B> Union<A, B>
+where
+    A: Iterator<Item = E>,
+    B: Iterator<Item = E>,
+{
+    /// Creates a new `Union` iterator.
+    ///
+    /// ```rust
+    /// use iter::Union;
+    ///
+    /// let evens = (1..).map(|n| n * 2);
+    /// let triples = (1..).map(|n| n * 3);
+    /// let mut it = Union::new(evens, triples);
+    /// assert_eq!(Some(2), it.next());
+    /// assert_eq!(Some(3), it.next());
+    /// assert_eq!(Some(4), it.next());
+    /// assert_eq!(Some(6), it.next()); // emitted only once
+    /// assert_eq!(Some(8), it.next());
+    /// assert_eq!(Some(9), it.next());
+    /// ```
+    pub fn new(a: A, b: B) -> Union<A, B> {
+        Union {
+            a: a.peekable(),
+            b: b.peekable(),
+        }
+    }
+}
+
+impl<E, A, B> Iterator for Union<A, B>
+where
+    E: Eq + Ord,
+    A: Iterator<Item = E>,
+    B: Iterator<Item = E>,
+{
+    type Item = E;
+
+    fn next(&mut self) -> Option<E> {
+        let cmp = match (self.a.peek(), self.b.peek()) {
+            (None, None) => return None,
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (Some(a), Some(b)) => a.cmp(b),
+        };
+        match cmp {
+            Ordering::Less => self.a.next(),
+            Ordering::Greater => self.b.next(),
+            Ordering::Equal => {
+                let _ = self.b.next();
+                self.a.next()
+            }
+        }
+    }
+}
+
+/// An iterator that enumerates elements contained in both of the two sorted iterators.
+pub struct Intersection<A, B>
+where
+    A: Iterator,
+    B: Iterator,
+{
+    a: A,
+    b: Peekable<B>,
+}
+
+impl<E, A, B> Intersection<A, B>
+where
+    A: Iterator<Item = E>,
+    B: Iterator<Item = E>,
+{
+    /// Creates a new `Intersection` iterator.
+    ///
+    /// ```rust
+    /// use iter::Intersection;
+    ///
+    /// let squares = (1..).map(|n| n * n);
+    /// let cubes = (1..).map(|n| n * n * n);
+    /// let mut it = Intersection::new(squares, cubes);
+    /// assert_eq!(Some(1), it.next()); // iterates sixth powers
+    /// assert_eq!(Some(64), it.next());
+    /// assert_eq!(Some(729), it.next());
+    /// ```
+    pub fn new(a: A, b: B) -> Intersection<A, B> {
+        Intersection { a, b: b.peekable() }
+    }
+}
+
+impl<E, A, B> Iterator for Intersection<A, B>
+where
+    E: Eq + Ord,
+    A: Iterator<Item = E>,
+    B: Iterator<Item = E>,
+{
+    type Item = E;
+
+    fn next(&mut self) -> Option<E> {
+        'a: loop {
+            let n = self.a.next()?;
+            'b: loop {
+                let cmp = match self.b.peek() {
+                    None => return None,
+                    Some(p) => n.cmp(p),
+                };
+                match cmp {
+                    Ordering::Less => continue 'a,
+                    Ordering::Equal => {
+                        let _ = self.b.next();
+                        return Some(n);
+                    }
+                    Ordering::Greater => {
+                        let _ = self.b.next();
+                        continue 'b;
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// An iterator that enumerates elements contained in exactly one of the two sorted iterators.
+pub struct SymmetricDifference<A, B>
+where
+    A: Iterator,
+    B: Iterator,
+{
+    a: Peekable<A>,
+    b: Peekable<B>,
+}
+
+impl<E, A, B> SymmetricDifference<A, B>
+where
+    A: Iterator<Item = E>,
+    B: Iterator<Item = E>,
+{
+    /// Creates a new `SymmetricDifference` iterator.
+    ///
+    /// ```rust
+    /// use iter::SymmetricDifference;
+    ///
+    /// let evens = (1..).map(|n| n * 2);
+    /// let triples = (1..).map(|n| n * 3);
+    /// let mut it = SymmetricDifference::new(evens, triples);
+    /// assert_eq!(Some(2), it.next());
+    /// assert_eq!(Some(3), it.next());
+    /// assert_eq!(Some(4), it.next());
+    /// assert_eq!(Some(8), it.next()); // 6 is contained in both
+    /// assert_eq!(Some(9), it.next());
+    /// assert_eq!(Some(10), it.next());
+    /// ```
+    pub fn new(a: A, b: B) -> SymmetricDifference<A, B> {
+        SymmetricDifference {
+            a: a.peekable(),
+            b: b.peekable(),
+        }
+    }
+}
+
+impl<E, A, B> Iterator for SymmetricDifference<A, B>
+where
+    E: Eq + Ord,
+    A: Iterator<Item = E>,
+    B: Iterator<Item = E>,
+{
+    type Item = E;
+
+    fn next(&mut self) -> Option<E> {
+        loop {
+            let cmp = match (self.a.peek(), self.b.peek()) {
+                (None, None) => return None,
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (Some(a), Some(b)) => a.cmp(b),
+            };
+            match cmp {
+                Ordering::Less => return self.a.next(),
+                Ordering::Greater => return self.b.next(),
+                Ordering::Equal => {
+                    let _ = self.a.next();
+                    let _ = self.b.next();
+                }
+            }
+        }
+    }
+}
+
+/// An iterator that merges any number of sorted iterators into one sorted iterator.
+pub struct Merge<I>
+where
+    I: Iterator,
+{
+    iters: Vec<I>,
+    heap: BinaryHeap<Reverse<(I::Item, usize)>>,
+    initialized: bool,
+    dedup: bool,
+}
+
+impl<E, I> Merge<I>
+where
+    E: Ord,
+    I: Iterator<Item = E>,
+{
+    /// Creates a new `Merge` iterator.
+    ///
+    /// Elements contained in several iterators are enumerated as many times as they appear.
+    ///
+    /// ```rust
+    /// use iter::Merge;
+    ///
+    /// let its = (2..5).map(|k| (1..).map(move |n| n * k));
+    /// let mut it = Merge::new(its);
+    /// assert_eq!(Some(2), it.next());
+    /// assert_eq!(Some(3), it.next());
+    /// assert_eq!(Some(4), it.next());
+    /// assert_eq!(Some(4), it.next());
+    /// assert_eq!(Some(6), it.next());
+    /// assert_eq!(Some(6), it.next());
+    /// ```
+    pub fn new<T>(iters: T) -> Merge<I>
+    where
+        T: IntoIterator<Item = I>,
+    {
+        Merge {
+            iters: iters.into_iter().collect(),
+            heap: BinaryHeap::new(),
+            initialized: false,
+            dedup: false,
+        }
+    }
+
+    /// Creates a new `Merge` iterator that enumerates each distinct element only once.
+    ///
+    /// ```rust
+    /// use iter::Merge;
+    ///
+    /// let its = (2..5).map(|k| (1..).map(move |n| n * k));
+    /// let mut it = Merge::new_dedup(its);
+    /// assert_eq!(Some(2), it.next());
+    /// assert_eq!(Some(3), it.next());
+    /// assert_eq!(Some(4), it.next());
+    /// assert_eq!(Some(6), it.next());
+    /// assert_eq!(Some(8), it.next());
+    /// assert_eq!(Some(9), it.next());
+    /// ```
+    pub fn new_dedup<T>(iters: T) -> Merge<I>
+    where
+        T: IntoIterator<Item = I>,
+    {
+        Merge {
+            dedup: true,
+            ..Merge::new(iters)
+        }
+    }
+
+    fn pull(&mut self, idx: usize) {
+        if let Some(e) = self.iters[idx].next() {
+            self.heap.push(Reverse((e, idx)));
+        }
+    }
+}
+
+impl<E, I> Iterator for Merge<I>
+where
+    E: Ord,
+    I: Iterator<Item = E>,
+{
+    type Item = E;
+
+    fn next(&mut self) -> Option<E> {
+        if !self.initialized {
+            self.initialized = true;
+            for idx in 0..self.iters.len() {
+                self.pull(idx);
+            }
+        }
+
+        let Reverse((e, idx)) = self.heap.pop()?;
+        self.pull(idx);
+
+        if self.dedup {
+            while let Some(Reverse((ref p, _))) = self.heap.peek() {
+                if *p != e {
+                    break;
+                }
+                let Reverse((_, idx)) = self.heap.pop().unwrap();
+                self.pull(idx);
+            }
+        }
+
+        Some(e)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::{BitCombination, CombinationOverlap, Permutations};
@@ -469,6 +771,117 @@ mod tests {
             assert_eq!(vec![1, 2, 3], diff.collect::<Vec<_>>());
         }
     }
+
+    mod union {
+        use super::super::Union;
+
+        #[test]
+        fn multiples() {
+            let a = (1..).map(|x| x * 2);
+            let b = (1..).map(|x| x * 3);
+            let union = Union::new(a, b);
+            assert_eq!(
+                vec![2, 3, 4, 6, 8, 9, 10, 12, 14, 15],
+                union.take(10).collect::<Vec<_>>()
+            );
+        }
+
+        #[test]
+        fn one_side_is_empty() {
+            let a = [1, 2, 3];
+            let b: [usize; 0] = [];
+            let union = Union::new(a.iter(), b.iter());
+            assert_eq!(vec![&1, &2, &3], union.collect::<Vec<_>>());
+            let union = Union::new(b.iter(), a.iter());
+            assert_eq!(vec![&1, &2, &3], union.collect::<Vec<_>>());
+        }
+    }
+
+    mod intersection {
+        use super::super::Intersection;
+
+        #[test]
+        fn sixth_powers() {
+            let sq = (1..).map(|x: u64| x * x);
+            let cb = (1..).map(|x: u64| x * x * x);
+            let inter = Intersection::new(sq, cb);
+            assert_eq!(vec![1, 64, 729, 4096], inter.take(4).collect::<Vec<_>>());
+        }
+
+        #[test]
+        fn finite() {
+            let a = vec![1, 3, 5, 7, 9];
+            let b = vec![2, 3, 4, 5, 10];
+            let inter = Intersection::new(a.into_iter(), b.into_iter());
+            assert_eq!(vec![3, 5], inter.collect::<Vec<_>>());
+        }
+
+        #[test]
+        fn one_side_is_empty() {
+            let a = [1, 2, 3];
+            let b: [usize; 0] = [];
+            assert!(Intersection::new(a.iter(), b.iter()).next().is_none());
+            assert!(Intersection::new(b.iter(), a.iter()).next().is_none());
+        }
+    }
+
+    mod symmetric_difference {
+        use super::super::SymmetricDifference;
+
+        #[test]
+        fn multiples() {
+            let a = (1..).map(|x| x * 2);
+            let b = (1..).map(|x| x * 3);
+            let diff = SymmetricDifference::new(a, b);
+            assert_eq!(
+                vec![2, 3, 4, 8, 9, 10, 14, 15, 16, 20],
+                diff.take(10).collect::<Vec<_>>()
+            );
+        }
+
+        #[test]
+        fn finite() {
+            let a = vec![1, 3, 5, 7, 9];
+            let b = vec![2, 3, 4, 5, 10];
+            let diff = SymmetricDifference::new(a.into_iter(), b.into_iter());
+            assert_eq!(vec![1, 2, 4, 7, 9, 10], diff.collect::<Vec<_>>());
+        }
+    }
+
+    mod merge {
+        use super::super::Merge;
+        use std::vec;
+
+        #[test]
+        fn finite() {
+            let its = vec![vec![1, 4, 7], vec![2, 4, 8], vec![], vec![3, 4, 9]];
+            let merge = Merge::new(its.into_iter().map(|v| v.into_iter()));
+            assert_eq!(vec![1, 2, 3, 4, 4, 4, 7, 8, 9], merge.collect::<Vec<_>>());
+        }
+
+        #[test]
+        fn finite_dedup() {
+            let its = vec![vec![1, 4, 7], vec![2, 4, 8], vec![], vec![3, 4, 9]];
+            let merge = Merge::new_dedup(its.into_iter().map(|v| v.into_iter()));
+            assert_eq!(vec![1, 2, 3, 4, 7, 8, 9], merge.collect::<Vec<_>>());
+        }
+
+        #[test]
+        fn infinite() {
+            let its = vec![2, 3, 5].into_iter().map(|p| (1..).map(move |n| n * p));
+            let merge = Merge::new_dedup(its);
+            assert_eq!(
+                vec![2, 3, 4, 5, 6, 8, 9, 10, 12, 14],
+                merge.take(10).collect::<Vec<_>>()
+            );
+        }
+
+        #[test]
+        fn no_iterators() {
+            let its: Vec<vec::IntoIter<u32>> = vec![];
+            assert!(Merge::new(its).next().is_none());
+        }
+    }
 }
 
 #[cfg(all(test, feature = "unstable"))]
@@ -0,0 +1,286 @@
+//! Deck of playing cards.
+
+use crate::Card;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/// Deck of playing cards.
+///
+/// Cards are dealt from the top of the deck. Dealt and burned cards are
+/// removed from the deck and never come back until a new deck is created.
+#[derive(Clone, Debug)]
+pub struct Deck {
+    cards: Vec<Card>,
+    top: usize,
+}
+
+impl Default for Deck {
+    fn default() -> Deck {
+        Deck::new()
+    }
+}
+
+impl Deck {
+    /// Creates a standard 52-card deck, ordered by the card index.
+    ///
+    /// ```rust
+    /// use playing_card::{deck::Deck, Card, Suit};
+    ///
+    /// let mut deck = Deck::new();
+    /// assert_eq!(52, deck.len());
+    /// assert_eq!(Some(Card::new(1, Suit::Spade)), deck.draw());
+    /// assert_eq!(Some(Card::new(2, Suit::Spade)), deck.draw());
+    /// ```
+    pub fn new() -> Deck {
+        Deck::from_cards((0..52).filter_map(Card::from_index).collect())
+    }
+
+    /// Creates a 54-card deck that contains black and white jokers.
+    pub fn with_jokers() -> Deck {
+        Deck::from_cards((0..54).filter_map(Card::from_index).collect())
+    }
+
+    /// Creates a deck that contains the given cards, the first card on the top.
+    pub fn from_cards(cards: Vec<Card>) -> Deck {
+        Deck { cards, top: 0 }
+    }
+
+    /// Creates a deck that contains the cards in the bitmask, ordered by the card index.
+    pub fn from_mask(mask: u64) -> Deck {
+        Deck::from_cards(
+            (0..54)
+                .filter(|&idx| mask & (1 << idx) != 0)
+                .filter_map(Card::from_index)
+                .collect(),
+        )
+    }
+
+    /// Returns the bitmask of the remaining cards.
+    ///
+    /// Cards without an index, whose number is not in `1..=13`, have no bit and
+    /// are left out.
+    pub fn to_mask(&self) -> u64 {
+        self.remaining()
+            .iter()
+            .filter_map(|card| card.to_mask())
+            .fold(0, |mask, bit| mask | bit)
+    }
+
+    /// Returns the remaining cards, the first card on the top.
+    pub fn remaining(&self) -> &[Card] {
+        &self.cards[self.top..]
+    }
+
+    /// Returns the number of the remaining cards.
+    pub fn len(&self) -> usize {
+        self.cards.len() - self.top
+    }
+
+    /// Returns `true` if no cards remain.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns `true` if the card remains in the deck.
+    pub fn contains(&self, card: &Card) -> bool {
+        self.remaining().contains(card)
+    }
+
+    /// Removes the card from the remaining cards wherever it is.
+    ///
+    /// Returns `false` if the card does not remain in the deck.
+    pub fn remove(&mut self, card: &Card) -> bool {
+        match self.remaining().iter().position(|c| c == card) {
+            Some(i) => {
+                let _ = self.cards.remove(self.top + i);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Shuffles the remaining cards by the Fisher-Yates algorithm.
+    pub fn shuffle<R: Rng + ?Sized>(&mut self, rng: &mut R) {
+        let cards = &mut self.cards[self.top..];
+        for i in (1..cards.len()).rev() {
+            let j = rng.gen_range(0, i + 1);
+            cards.swap(i, j);
+        }
+    }
+
+    /// Shuffles the remaining cards with a random number generator seeded by `seed`.
+    ///
+    /// The same seed always gives the same order.
+    ///
+    /// ```rust
+    /// use playing_card::deck::Deck;
+    ///
+    /// let mut d0 = Deck::new();
+    /// let mut d1 = Deck::new();
+    /// d0.shuffle_with_seed(1234);
+    /// d1.shuffle_with_seed(1234);
+    /// assert_eq!(d0.remaining(), d1.remaining());
+    /// ```
+    pub fn shuffle_with_seed(&mut self, seed: u64) {
+        self.shuffle(&mut StdRng::seed_from_u64(seed))
+    }
+
+    /// Deals the top card.
+    pub fn draw(&mut self) -> Option<Card> {
+        let card = *self.cards.get(self.top)?;
+        self.top += 1;
+        Some(card)
+    }
+
+    /// Deals `n` cards from the top.
+    ///
+    /// Returns `None` and deals nothing if less than `n` cards remain.
+    pub fn deal(&mut self, n: usize) -> Option<Vec<Card>> {
+        if self.len() < n {
+            return None;
+        }
+        let cards = self.cards[self.top..(self.top + n)].to_vec();
+        self.top += n;
+        Some(cards)
+    }
+
+    /// Deals `cards_per_hand` cards to each of `num_hands` players, one card at a time.
+    ///
+    /// Returns `None` and deals nothing if not enough cards remain.
+    ///
+    /// ```rust
+    /// use playing_card::deck::Deck;
+    ///
+    /// let mut deck = Deck::new();
+    /// let hands = deck.deal_hands(2, 3).unwrap();
+    /// assert_eq!("AS 3S 5S", hands[0].iter().map(|c| c.to_string()).collect::<Vec<_>>().join(" "));
+    /// assert_eq!("2S 4S 6S", hands[1].iter().map(|c| c.to_string()).collect::<Vec<_>>().join(" "));
+    /// assert_eq!(46, deck.len());
+    /// ```
+    pub fn deal_hands(
+        &mut self,
+        num_hands: usize,
+        cards_per_hand: usize,
+    ) -> Option<Vec<Vec<Card>>> {
+        let cards = self.deal(num_hands * cards_per_hand)?;
+        let hands = (0..num_hands)
+            .map(|i| cards.iter().skip(i).step_by(num_hands).cloned().collect())
+            .collect();
+        Some(hands)
+    }
+
+    /// Discards `n` cards from the top.
+    ///
+    /// Returns `false` and discards nothing if less than `n` cards remain.
+    pub fn burn(&mut self, n: usize) -> bool {
+        self.deal(n).is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Deck;
+    use crate::{Card, Suit};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn new() {
+        let deck = Deck::new();
+        assert_eq!(52, deck.len());
+        assert_eq!((1 << 52) - 1, deck.to_mask());
+        assert!(!deck.contains(&Card::BlackJoker));
+
+        let deck = Deck::with_jokers();
+        assert_eq!(54, deck.len());
+        assert_eq!((1 << 54) - 1, deck.to_mask());
+        assert!(deck.contains(&Card::BlackJoker));
+        assert!(deck.contains(&Card::WhiteJoker));
+    }
+
+    #[test]
+    fn mask() {
+        let mask =
+            Card::new(1, Suit::Spade).to_mask().unwrap() | Card::WhiteJoker.to_mask().unwrap();
+        let deck = Deck::from_mask(mask);
+        assert_eq!(
+            &[Card::new(1, Suit::Spade), Card::WhiteJoker],
+            deck.remaining()
+        );
+        assert_eq!(mask, deck.to_mask());
+    }
+
+    #[test]
+    fn shuffle() {
+        let mut d0 = Deck::new();
+        d0.shuffle(&mut StdRng::seed_from_u64(0));
+        assert_eq!(52, d0.len());
+        assert_eq!(Deck::new().to_mask(), d0.to_mask());
+        assert_ne!(Deck::new().remaining(), d0.remaining());
+
+        let mut d1 = Deck::new();
+        d1.shuffle_with_seed(0);
+        assert_eq!(d0.remaining(), d1.remaining());
+
+        let mut d2 = Deck::new();
+        d2.shuffle_with_seed(1);
+        assert_ne!(d0.remaining(), d2.remaining());
+    }
+
+    #[test]
+    fn shuffle_keeps_dealt_cards() {
+        let mut deck = Deck::new();
+        let top = deck.deal(5).unwrap();
+        deck.shuffle_with_seed(0);
+        assert_eq!(47, deck.len());
+        assert!(top.iter().all(|c| !deck.contains(c)));
+    }
+
+    #[test]
+    fn deal() {
+        let mut deck = Deck::new();
+        assert_eq!(Some(Card::new(1, Suit::Spade)), deck.draw());
+        assert!(deck.burn(2));
+        assert_eq!(
+            Some(vec![Card::new(4, Suit::Spade), Card::new(5, Suit::Spade)]),
+            deck.deal(2)
+        );
+        assert_eq!(47, deck.len());
+
+        assert_eq!(None, deck.deal(48));
+        assert!(!deck.burn(48));
+        assert_eq!(47, deck.len());
+
+        assert!(deck.burn(47));
+        assert!(deck.is_empty());
+        assert_eq!(None, deck.draw());
+        assert_eq!(Some(vec![]), deck.deal(0));
+    }
+
+    #[test]
+    fn deal_hands() {
+        let mut deck = Deck::new();
+        let hands = deck.deal_hands(4, 13).unwrap();
+        assert!(deck.is_empty());
+        assert_eq!(4, hands.len());
+        for (i, hand) in hands.iter().enumerate() {
+            assert_eq!(13, hand.len());
+            for (j, card) in hand.iter().enumerate() {
+                assert_eq!(Some((j * 4 + i) as u8), card.to_index());
+            }
+        }
+        assert_eq!(None, deck.deal_hands(1, 1));
+    }
+
+    #[test]
+    fn remove() {
+        let mut deck = Deck::new();
+        let card = Card::new(13, Suit::Dia);
+        assert!(deck.remove(&card));
+        assert!(!deck.remove(&card));
+        assert!(!deck.contains(&card));
+        assert_eq!(51, deck.len());
+        assert_eq!(
+            Deck::new().to_mask() & !card.to_mask().unwrap(),
+            deck.to_mask()
+        );
+    }
+}
@@ -0,0 +1,461 @@
+//! Equity calculator for poker scenarios.
+
+use crate::{deck::Deck, poker::Hand, Card, SuitCard};
+use num_rational::Ratio;
+use rand::{rngs::StdRng, SeedableRng};
+use std::{error::Error, fmt};
+
+/// Number of cards on a complete board.
+pub const BOARD_SIZE: usize = 5;
+
+/// Win, tie and loss counts of a player.
+#[derive(Eq, PartialEq, Copy, Clone, Debug, Default)]
+pub struct Outcome {
+    /// Number of boards on which the player has the only best hand.
+    pub win: u64,
+    /// Number of boards on which the player shares the best hand with others.
+    pub tie: u64,
+    /// Number of boards on which another player has a better hand.
+    pub loss: u64,
+}
+
+impl Outcome {
+    /// Returns the number of evaluated boards.
+    pub fn total(&self) -> u64 {
+        self.win + self.tie + self.loss
+    }
+
+    /// Returns the ratio of the boards the player wins.
+    pub fn win_ratio(&self) -> Ratio<u64> {
+        Ratio::new(self.win, self.total())
+    }
+
+    /// Returns the ratio of the boards the player ties.
+    pub fn tie_ratio(&self) -> Ratio<u64> {
+        Ratio::new(self.tie, self.total())
+    }
+
+    /// Returns the ratio of the boards the player loses.
+    pub fn loss_ratio(&self) -> Ratio<u64> {
+        Ratio::new(self.loss, self.total())
+    }
+}
+
+/// Why an equity calculation cannot be set up.
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+pub enum EquityError {
+    /// No hands are given.
+    NoHands,
+    /// The board has more than `BOARD_SIZE` cards.
+    TooManyBoardCards(usize),
+    /// The number of the card is not in `1..=13`.
+    InvalidCard(SuitCard),
+    /// The card appears twice in the hands and the board.
+    DuplicatedCard(SuitCard),
+    /// The number of the sampled boards is zero.
+    NoSamples,
+    /// The deck has fewer cards left than are needed to complete the board.
+    NotEnoughCards {
+        /// Number of the cards needed to complete the board.
+        needed: usize,
+        /// Number of the cards left in the deck.
+        left: usize,
+    },
+}
+
+impl fmt::Display for EquityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            EquityError::NoHands => write!(f, "no hands are given"),
+            EquityError::TooManyBoardCards(n) => {
+                write!(f, "the board has {} cards, more than {}", n, BOARD_SIZE)
+            }
+            EquityError::InvalidCard(c) => write!(f, "{} has an invalid number", c),
+            EquityError::DuplicatedCard(c) => write!(f, "{} is used twice", c),
+            EquityError::NoSamples => write!(f, "no boards are sampled"),
+            EquityError::NotEnoughCards { needed, left } => write!(
+                f,
+                "{} cards are needed to complete the board, but only {} are left",
+                needed, left
+            ),
+        }
+    }
+}
+
+impl Error for EquityError {}
+
+/// Result of an equity calculation.
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub struct Report {
+    /// Outcomes of each player, in the order of the given hands.
+    pub outcomes: Vec<Outcome>,
+    /// `true` if all possible boards are enumerated, `false` if boards are sampled.
+    ///
+    /// The ratios of the outcomes are the exact probabilities only if this is `true`.
+    pub is_exact: bool,
+}
+
+/// Equity calculator that evaluates the hands of several players against a
+/// partially known board.
+///
+/// Each player makes the best five-card hand from their own cards and the
+/// board, as in Texas hold'em.
+///
+/// ```rust
+/// use num_rational::Ratio;
+/// use playing_card::{equity::Equity, poker::parse_cards};
+///
+/// let hands = [parse_cards("AH AS").unwrap(), parse_cards("KH KS").unwrap()];
+/// let board = parse_cards("2C 7D 9H TC").unwrap();
+/// let report = Equity::new(&hands, &board).unwrap().calculate();
+/// assert!(report.is_exact);
+/// assert_eq!(Ratio::new(21, 22), report.outcomes[0].win_ratio());
+/// assert_eq!(Ratio::new(1, 22), report.outcomes[1].win_ratio());
+/// ```
+#[derive(Clone, Debug)]
+pub struct Equity<'a> {
+    hands: &'a [Vec<SuitCard>],
+    board: &'a [SuitCard],
+    max_boards: u64,
+    samples: u64,
+    seed: u64,
+}
+
+impl<'a> Equity<'a> {
+    /// Creates a new calculator.
+    ///
+    /// Fails if no hands are given, the board has more than `BOARD_SIZE` cards,
+    /// a card has a number out of `1..=13`, the same card appears twice or too
+    /// few cards are left to complete the board.
+    pub fn new(
+        hands: &'a [Vec<SuitCard>],
+        board: &'a [SuitCard],
+    ) -> Result<Equity<'a>, EquityError> {
+        if hands.is_empty() {
+            return Err(EquityError::NoHands);
+        }
+        if board.len() > BOARD_SIZE {
+            return Err(EquityError::TooManyBoardCards(board.len()));
+        }
+
+        let mut mask = 0;
+        for &c in hands.iter().flat_map(|h| h.iter()).chain(board) {
+            let bit = match Card::Suit(c).to_mask() {
+                Some(bit) => bit,
+                None => return Err(EquityError::InvalidCard(c)),
+            };
+            if mask & bit != 0 {
+                return Err(EquityError::DuplicatedCard(c));
+            }
+            mask |= bit;
+        }
+
+        let equity = Equity {
+            hands,
+            board,
+            max_boards: 100_000,
+            samples: 10_000,
+            seed: 0,
+        };
+        let needed = BOARD_SIZE - board.len();
+        let left = equity.deck().len();
+        if left < needed {
+            return Err(EquityError::NotEnoughCards { needed, left });
+        }
+        Ok(equity)
+    }
+
+    /// Sets the maximum number of boards `calculate` enumerates exactly.
+    pub fn max_boards(self, max_boards: u64) -> Equity<'a> {
+        Equity { max_boards, ..self }
+    }
+
+    /// Sets the number of boards `sample` evaluates.
+    ///
+    /// Fails if `samples` is zero, as no ratios can be given for no boards.
+    pub fn samples(self, samples: u64) -> Result<Equity<'a>, EquityError> {
+        if samples == 0 {
+            return Err(EquityError::NoSamples);
+        }
+        Ok(Equity { samples, ..self })
+    }
+
+    /// Sets the seed of the random number generator `sample` uses.
+    pub fn seed(self, seed: u64) -> Equity<'a> {
+        Equity { seed, ..self }
+    }
+
+    /// Returns the number of the possible complete boards.
+    pub fn num_boards(&self) -> u64 {
+        let n = self.deck().len() as u64;
+        let k = (BOARD_SIZE - self.board.len()) as u64;
+        (0..k).fold(1, |acc, i| acc * (n - i) / (i + 1))
+    }
+
+    /// Enumerates all possible boards if there are not more than the maximum
+    /// number of them, samples boards otherwise.
+    pub fn calculate(&self) -> Report {
+        if self.num_boards() <= self.max_boards {
+            self.enumerate()
+        } else {
+            self.sample()
+        }
+    }
+
+    /// Enumerates all possible boards.
+    pub fn enumerate(&self) -> Report {
+        let rest = self
+            .deck()
+            .remaining()
+            .iter()
+            .map(to_suit_card)
+            .collect::<Vec<_>>();
+        let k = BOARD_SIZE - self.board.len();
+
+        let mut outcomes = vec![Outcome::default(); self.hands.len()];
+        let mut board = self.board.to_vec();
+        let mut idxs = (0..k).collect::<Vec<_>>();
+        loop {
+            board.truncate(self.board.len());
+            board.extend(idxs.iter().map(|&i| rest[i]));
+            self.evaluate(&board, &mut outcomes);
+
+            match (0..k).rposition(|i| idxs[i] < rest.len() - k + i) {
+                Some(i) => {
+                    idxs[i] += 1;
+                    for j in (i + 1)..k {
+                        idxs[j] = idxs[j - 1] + 1;
+                    }
+                }
+                None => break,
+            }
+        }
+
+        Report {
+            outcomes,
+            is_exact: true,
+        }
+    }
+
+    /// Evaluates randomly sampled boards.
+    pub fn sample(&self) -> Report {
+        let mut rng = StdRng::seed_from_u64(self.seed);
+        let deck = self.deck();
+        let k = BOARD_SIZE - self.board.len();
+
+        let mut outcomes = vec![Outcome::default(); self.hands.len()];
+        let mut board = self.board.to_vec();
+        for _ in 0..self.samples {
+            let mut deck = deck.clone();
+            deck.shuffle(&mut rng);
+            board.truncate(self.board.len());
+            board.extend(deck.deal(k).unwrap().iter().map(to_suit_card));
+            self.evaluate(&board, &mut outcomes);
+        }
+
+        Report {
+            outcomes,
+            is_exact: false,
+        }
+    }
+
+    fn deck(&self) -> Deck {
+        let mut deck = Deck::new();
+        for &c in self.hands.iter().flat_map(|h| h.iter()).chain(self.board) {
+            let _ = deck.remove(&Card::Suit(c));
+        }
+        deck
+    }
+
+    fn evaluate(&self, board: &[SuitCard], outcomes: &mut [Outcome]) {
+        let hands = self
+            .hands
+            .iter()
+            .map(|h| {
+                let mut cards = h.clone();
+                cards.extend_from_slice(board);
+                Hand::best_of(&cards).unwrap()
+            })
+            .collect::<Vec<_>>();
+        let best = hands.iter().max().unwrap();
+        let num_best = hands.iter().filter(|&h| h == best).count();
+
+        for (h, o) in hands.iter().zip(outcomes.iter_mut()) {
+            if h != best {
+                o.loss += 1;
+            } else if num_best == 1 {
+                o.win += 1;
+            } else {
+                o.tie += 1;
+            }
+        }
+    }
+}
+
+fn to_suit_card(card: &Card) -> SuitCard {
+    match *card {
+        Card::Suit(sc) => sc,
+        _ => unreachable!(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Equity, EquityError, Outcome};
+    use crate::poker::parse_cards;
+    use num_rational::Ratio;
+
+    fn hands(ss: &[&str]) -> Vec<Vec<crate::SuitCard>> {
+        ss.iter().map(|s| parse_cards(s).unwrap()).collect()
+    }
+
+    #[test]
+    fn complete_board() {
+        let hs = hands(&["AH AS", "KH KS"]);
+        let board = parse_cards("2C 7D 9H TC KD").unwrap();
+        let eq = Equity::new(&hs, &board).unwrap();
+        assert_eq!(1, eq.num_boards());
+        let report = eq.calculate();
+        assert!(report.is_exact);
+        assert_eq!(
+            vec![
+                Outcome {
+                    win: 0,
+                    tie: 0,
+                    loss: 1
+                },
+                Outcome {
+                    win: 1,
+                    tie: 0,
+                    loss: 0
+                },
+            ],
+            report.outcomes
+        );
+    }
+
+    #[test]
+    fn tie() {
+        let hs = hands(&["2C 3D", "4H 5H", "7C 8D"]);
+        let board = parse_cards("AS KS QS JS TS").unwrap();
+        let report = Equity::new(&hs, &board).unwrap().calculate();
+        for o in &report.outcomes {
+            assert_eq!(Ratio::from_integer(1), o.tie_ratio());
+        }
+    }
+
+    #[test]
+    fn turn() {
+        let hs = hands(&["AH AS", "KH KS"]);
+        let board = parse_cards("2C 7D 9H TC").unwrap();
+        let eq = Equity::new(&hs, &board).unwrap();
+        assert_eq!(44, eq.num_boards());
+        let report = eq.enumerate();
+        assert_eq!(Ratio::new(21, 22), report.outcomes[0].win_ratio());
+        assert_eq!(Ratio::new(1, 22), report.outcomes[0].loss_ratio());
+        assert_eq!(Ratio::new(1, 22), report.outcomes[1].win_ratio());
+        assert_eq!(Ratio::from_integer(0), report.outcomes[1].tie_ratio());
+    }
+
+    #[test]
+    fn flop() {
+        let hs = hands(&["AH KH", "QC QD"]);
+        let board = parse_cards("2H 7H QS").unwrap();
+        let eq = Equity::new(&hs, &board).unwrap();
+        assert_eq!(990, eq.num_boards());
+        let report = eq.calculate();
+        assert!(report.is_exact);
+        for o in &report.outcomes {
+            assert_eq!(990, o.total());
+        }
+        assert_eq!(report.outcomes[0].win, report.outcomes[1].loss);
+        assert_eq!(report.outcomes[0].loss, report.outcomes[1].win);
+    }
+
+    #[test]
+    fn sample() {
+        let hs = hands(&["AH AS", "KH KS"]);
+        let eq = Equity::new(&hs, &[])
+            .unwrap()
+            .samples(20_000)
+            .unwrap()
+            .seed(1);
+        assert_eq!(1_712_304, eq.num_boards());
+
+        let report = eq.calculate();
+        assert!(!report.is_exact);
+        assert_eq!(20_000, report.outcomes[0].total());
+        let win = report.outcomes[0].win_ratio();
+        assert!(Ratio::new(78, 100) < win && win < Ratio::new(86, 100));
+
+        assert_eq!(report, eq.sample());
+        assert_ne!(report, eq.clone().seed(2).sample());
+    }
+
+    #[test]
+    fn max_boards() {
+        let hs = hands(&["AH AS", "KH KS"]);
+        let board = parse_cards("2C 7D 9H").unwrap();
+        assert!(Equity::new(&hs, &board).unwrap().calculate().is_exact);
+        assert!(
+            !Equity::new(&hs, &board)
+                .unwrap()
+                .max_boards(100)
+                .samples(100)
+                .unwrap()
+                .calculate()
+                .is_exact
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(EquityError::NoHands, Equity::new(&[], &[]).unwrap_err());
+
+        let hs = hands(&["AH AS", "AH KS"]);
+        assert_eq!(
+            EquityError::DuplicatedCard("AH".parse().unwrap()),
+            Equity::new(&hs, &[]).unwrap_err()
+        );
+
+        let hs = hands(&["AH AS"]);
+        let board = parse_cards("2C 3C 4C 5C 6C 7C").unwrap();
+        assert_eq!(
+            EquityError::TooManyBoardCards(6),
+            Equity::new(&hs, &board).unwrap_err()
+        );
+
+        let hs = hands(&["AH AS"]);
+        assert_eq!(
+            EquityError::NoSamples,
+            Equity::new(&hs, &[]).unwrap().samples(0).unwrap_err()
+        );
+
+        let mut hs = hands(&["AH AS"]);
+        hs[0][1].num = 14;
+        assert_eq!(
+            EquityError::InvalidCard(hs[0][1]),
+            Equity::new(&hs, &[]).unwrap_err()
+        );
+        hs[0][1].num = 0;
+        assert_eq!(
+            EquityError::InvalidCard(hs[0][1]),
+            Equity::new(&hs, &[]).unwrap_err()
+        );
+
+        let deck = crate::deck::Deck::new()
+            .remaining()
+            .iter()
+            .map(super::to_suit_card)
+            .collect::<Vec<_>>();
+        let hs = vec![deck[..47].to_vec()];
+        let board = deck[47..51].to_vec();
+        assert!(Equity::new(&hs, &board).is_ok());
+        let hs = vec![deck[..49].to_vec()];
+        let board = deck[49..51].to_vec();
+        assert_eq!(
+            EquityError::NotEnoughCards { needed: 3, left: 1 },
+            Equity::new(&hs, &board).unwrap_err()
+        );
+    }
+}
@@ -0,0 +1,280 @@
+//! Human-friendly formats of playing cards.
+//!
+//! The `Display` and `FromStr` implementations of the card types use compact
+//! two-letter forms such as `AS` and `TH`. The wrappers in this module render
+//! and parse other forms.
+
+use crate::{
+    Card, Suit,
+    Suit::{Club, Dia, Heart, Spade},
+    SuitCard,
+};
+use std::{fmt, str::FromStr};
+
+/// Unicode format, such as `A♠`, `10♥` and `🃏`.
+///
+/// ```rust
+/// use playing_card::{format::Unicode, Card, Suit};
+///
+/// assert_eq!("A♠", Unicode(Card::new(1, Suit::Spade)).to_string());
+/// assert_eq!(Ok(Unicode(Card::new(10, Suit::Heart))), "10♥".parse());
+/// ```
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+pub struct Unicode<T>(pub T);
+
+/// Full name format, such as `Ace of Spades` and `Black Joker`.
+///
+/// Parsing is case-insensitive.
+///
+/// ```rust
+/// use playing_card::{format::Name, Card, Suit};
+///
+/// assert_eq!("Ace of Spades", Name(Card::new(1, Suit::Spade)).to_string());
+/// assert_eq!(Ok(Name(Card::new(10, Suit::Heart))), "ten of hearts".parse());
+/// ```
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+pub struct Name<T>(pub T);
+
+const SUIT_SYMBOLS: [(Suit, &str); 4] = [(Spade, "♠"), (Heart, "♥"), (Dia, "♦"), (Club, "♣")];
+const SUIT_NAMES: [(Suit, &str); 4] = [
+    (Spade, "Spades"),
+    (Heart, "Hearts"),
+    (Dia, "Diamonds"),
+    (Club, "Clubs"),
+];
+const NUM_SYMBOLS: [&str; 13] = [
+    "A", "2", "3", "4", "5", "6", "7", "8", "9", "10", "J", "Q", "K",
+];
+const NUM_NAMES: [&str; 13] = [
+    "Ace", "Two", "Three", "Four", "Five", "Six", "Seven", "Eight", "Nine", "Ten", "Jack", "Queen",
+    "King",
+];
+const BLACK_JOKER_SYMBOL: &str = "🃏";
+const WHITE_JOKER_SYMBOL: &str = "🃟";
+const BLACK_JOKER_NAME: &str = "Black Joker";
+const WHITE_JOKER_NAME: &str = "White Joker";
+
+fn suit_str(table: &[(Suit, &'static str)], suit: Suit) -> &'static str {
+    table.iter().find(|&&(s, _)| s == suit).unwrap().1
+}
+
+fn parse_suit(table: &[(Suit, &str)], s: &str) -> Result<Suit, ()> {
+    table
+        .iter()
+        .find(|&&(_, t)| t.eq_ignore_ascii_case(s))
+        .map(|&(suit, _)| suit)
+        .ok_or(())
+}
+
+// Numbers out of `1..=13` are written as they are, as in the compact form.
+fn write_num(f: &mut fmt::Formatter<'_>, table: &[&str], num: u8) -> fmt::Result {
+    match (num as usize).checked_sub(1).and_then(|i| table.get(i)) {
+        Some(s) => write!(f, "{}", s),
+        None => write!(f, "{}", num),
+    }
+}
+
+fn parse_num(table: &[&str], s: &str) -> Result<u8, ()> {
+    table
+        .iter()
+        .position(|t| t.eq_ignore_ascii_case(s))
+        .map(|i| i as u8 + 1)
+        .ok_or(())
+}
+
+impl fmt::Display for Unicode<Suit> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", suit_str(&SUIT_SYMBOLS, self.0))
+    }
+}
+
+impl FromStr for Unicode<Suit> {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Unicode<Suit>, ()> {
+        parse_suit(&SUIT_SYMBOLS, s).map(Unicode)
+    }
+}
+
+impl fmt::Display for Unicode<SuitCard> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let SuitCard { num, suit } = self.0;
+        write_num(f, &NUM_SYMBOLS, num)?;
+        write!(f, "{}", Unicode(suit))
+    }
+}
+
+impl FromStr for Unicode<SuitCard> {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Unicode<SuitCard>, ()> {
+        let (i, _) = s.char_indices().last().ok_or(())?;
+        let num = match &s[..i] {
+            "T" => 10,
+            n => parse_num(&NUM_SYMBOLS, n)?,
+        };
+        let Unicode(suit) = s[i..].parse()?;
+        Ok(Unicode(SuitCard { num, suit }))
+    }
+}
+
+impl fmt::Display for Unicode<Card> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Card::Suit(sc) => write!(f, "{}", Unicode(sc)),
+            Card::BlackJoker => write!(f, "{}", BLACK_JOKER_SYMBOL),
+            Card::WhiteJoker => write!(f, "{}", WHITE_JOKER_SYMBOL),
+        }
+    }
+}
+
+impl FromStr for Unicode<Card> {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Unicode<Card>, ()> {
+        match s {
+            BLACK_JOKER_SYMBOL => Ok(Unicode(Card::BlackJoker)),
+            WHITE_JOKER_SYMBOL => Ok(Unicode(Card::WhiteJoker)),
+            _ => s.parse().map(|Unicode(sc)| Unicode(Card::Suit(sc))),
+        }
+    }
+}
+
+impl fmt::Display for Name<Suit> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", suit_str(&SUIT_NAMES, self.0))
+    }
+}
+
+impl FromStr for Name<Suit> {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Name<Suit>, ()> {
+        parse_suit(&SUIT_NAMES, s).map(Name)
+    }
+}
+
+impl fmt::Display for Name<SuitCard> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let SuitCard { num, suit } = self.0;
+        write_num(f, &NUM_NAMES, num)?;
+        write!(f, " of {}", Name(suit))
+    }
+}
+
+impl FromStr for Name<SuitCard> {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Name<SuitCard>, ()> {
+        let mut it = s.split_whitespace();
+        match (it.next(), it.next(), it.next(), it.next()) {
+            (Some(num), Some(of), Some(suit), None) if of.eq_ignore_ascii_case("of") => {
+                let num = parse_num(&NUM_NAMES, num)?;
+                let Name(suit) = suit.parse()?;
+                Ok(Name(SuitCard { num, suit }))
+            }
+            _ => Err(()),
+        }
+    }
+}
+
+impl fmt::Display for Name<Card> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Card::Suit(sc) => write!(f, "{}", Name(sc)),
+            Card::BlackJoker => write!(f, "{}", BLACK_JOKER_NAME),
+            Card::WhiteJoker => write!(f, "{}", WHITE_JOKER_NAME),
+        }
+    }
+}
+
+impl FromStr for Name<Card> {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Name<Card>, ()> {
+        let words = s.split_whitespace().collect::<Vec<_>>().join(" ");
+        if words.eq_ignore_ascii_case(BLACK_JOKER_NAME) {
+            return Ok(Name(Card::BlackJoker));
+        }
+        if words.eq_ignore_ascii_case(WHITE_JOKER_NAME) {
+            return Ok(Name(Card::WhiteJoker));
+        }
+        s.parse().map(|Name(sc)| Name(Card::Suit(sc)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Name, Unicode};
+    use crate::{Card, Suit, SuitCard};
+
+    #[test]
+    fn unicode() {
+        fn check(s: &str, card: Card) {
+            assert_eq!(s, Unicode(card).to_string());
+            assert_eq!(Ok(Unicode(card)), s.parse());
+        }
+        check("A♠", Card::new(1, Suit::Spade));
+        check("10♥", Card::new(10, Suit::Heart));
+        check("Q♦", Card::new(12, Suit::Dia));
+        check("2♣", Card::new(2, Suit::Club));
+        check("🃏", Card::BlackJoker);
+        check("🃟", Card::WhiteJoker);
+
+        assert_eq!(Ok(Unicode(Card::new(10, Suit::Club))), "T♣".parse());
+        assert_eq!(Err(()), "".parse::<Unicode<Card>>());
+        assert_eq!(Err(()), "♠".parse::<Unicode<Card>>());
+        assert_eq!(Err(()), "1♠".parse::<Unicode<Card>>());
+        assert_eq!(Err(()), "AS".parse::<Unicode<Card>>());
+    }
+
+    #[test]
+    fn invalid_number() {
+        let card = SuitCard {
+            num: 0,
+            suit: Suit::Spade,
+        };
+        assert_eq!("0♠", Unicode(card).to_string());
+        assert_eq!("0 of Spades", Name(card).to_string());
+        let card = SuitCard {
+            num: 14,
+            suit: Suit::Heart,
+        };
+        assert_eq!("14♥", Unicode(card).to_string());
+        assert_eq!("14 of Hearts", Name(card).to_string());
+    }
+
+    #[test]
+    fn name() {
+        fn check(s: &str, card: Card) {
+            assert_eq!(s, Name(card).to_string());
+            assert_eq!(Ok(Name(card)), s.parse());
+        }
+        check("Ace of Spades", Card::new(1, Suit::Spade));
+        check("Ten of Hearts", Card::new(10, Suit::Heart));
+        check("Queen of Diamonds", Card::new(12, Suit::Dia));
+        check("Two of Clubs", Card::new(2, Suit::Club));
+        check("Black Joker", Card::BlackJoker);
+        check("White Joker", Card::WhiteJoker);
+
+        assert_eq!(
+            Ok(Name(Card::new(13, Suit::Club))),
+            "king  OF clubs".parse()
+        );
+        assert_eq!(Ok(Name(Card::BlackJoker)), "black joker".parse());
+        assert_eq!(Err(()), "Ace Spades".parse::<Name<Card>>());
+        assert_eq!(Err(()), "One of Spades".parse::<Name<Card>>());
+        assert_eq!(Err(()), "Ace of Spades and".parse::<Name<Card>>());
+    }
+
+    #[test]
+    fn round_trip() {
+        for idx in 0..52 {
+            let card = SuitCard::from_index(idx).unwrap();
+            let s = Unicode(card).to_string();
+            assert_eq!(Ok(Unicode(card)), s.parse());
+            let s = Name(card).to_string();
+            assert_eq!(Ok(Name(card)), s.parse());
+        }
+    }
+}
@@ -13,10 +13,16 @@
 use crate::Suit::{Club, Dia, Heart, Spade};
 use std::{fmt, str::FromStr};
 
+pub mod deck;
+pub mod equity;
+pub mod format;
+pub mod order;
+pub mod poker;
+
 /// Playing card's suite.
 #[allow(missing_docs, unused_qualifications)]
 // FIXME rust-lang/rust#19102
-#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Copy, Clone, Debug)]
 pub enum Suit {
     Spade,
     Heart,
@@ -55,8 +61,11 @@ impl FromStr for Suit {
 }
 
 /// Playing card that only contains suit cards.
+///
+/// Cards are ordered by their numbers (aces are low) and then by their suits.
+/// See the `order` module for other orderings.
 #[allow(missing_docs)]
-#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Copy, Clone, Debug)]
 pub struct SuitCard {
     pub num: u8,
     pub suit: Suit,
@@ -102,9 +111,39 @@ impl FromStr for SuitCard {
     }
 }
 
+impl SuitCard {
+    /// Returns the compact index of the card in `0..52`.
+    ///
+    /// Cards are numbered from the ace to the king in each suit, and suits are
+    /// ordered as spades, hearts, diamonds and clubs. Returns `None` if the
+    /// number is not in `1..=13`.
+    pub fn to_index(self) -> Option<u8> {
+        if (1..=13).contains(&self.num) {
+            Some(self.suit as u8 * 13 + (self.num - 1))
+        } else {
+            None
+        }
+    }
+
+    /// Creates the card from its compact index.
+    pub fn from_index(idx: u8) -> Option<SuitCard> {
+        let suit = match idx / 13 {
+            0 => Spade,
+            1 => Heart,
+            2 => Dia,
+            3 => Club,
+            _ => return None,
+        };
+        Some(SuitCard {
+            num: idx % 13 + 1,
+            suit,
+        })
+    }
+}
+
 /// Playing card that also contaiins jokers.
 #[allow(missing_docs)]
-#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Copy, Clone, Debug)]
 pub enum Card {
     Suit(SuitCard),
     BlackJoker,
@@ -138,6 +177,33 @@ impl Card {
     pub fn new(n: u8, s: Suit) -> Card {
         Card::Suit(SuitCard { num: n, suit: s })
     }
+
+    /// Returns the compact index of the card in `0..54`.
+    ///
+    /// Suit cards have the same index as `SuitCard::to_index`, and the black
+    /// and white jokers are numbered 52 and 53.
+    pub fn to_index(self) -> Option<u8> {
+        match self {
+            Card::Suit(sc) => sc.to_index(),
+            Card::BlackJoker => Some(52),
+            Card::WhiteJoker => Some(53),
+        }
+    }
+
+    /// Creates the card from its compact index.
+    pub fn from_index(idx: u8) -> Option<Card> {
+        match idx {
+            52 => Some(Card::BlackJoker),
+            53 => Some(Card::WhiteJoker),
+            _ => SuitCard::from_index(idx).map(Card::Suit),
+        }
+    }
+
+    /// Returns the bit that represents the card in a set of cards, or `None`
+    /// if the card has no index.
+    pub fn to_mask(self) -> Option<u64> {
+        self.to_index().map(|idx| 1 << idx)
+    }
 }
 
 #[cfg(test)]
@@ -145,6 +211,7 @@ mod tests {
     use super::{
         Card, Suit,
         Suit::{Club, Dia, Heart, Spade},
+        SuitCard,
     };
 
     #[test]
@@ -175,4 +242,30 @@ mod tests {
         check_pair("QC".to_string(), Card::new(12, Club));
         check_pair("KD".to_string(), Card::new(13, Dia));
     }
+
+    #[test]
+    fn index() {
+        for idx in 0..54 {
+            let card = Card::from_index(idx).unwrap();
+            assert_eq!(Some(idx), card.to_index());
+            assert_eq!(Some(1 << idx), card.to_mask());
+        }
+        assert_eq!(None, Card::from_index(54));
+        assert_eq!(None, SuitCard::from_index(52));
+
+        assert_eq!(Some(0), Card::new(1, Spade).to_index());
+        assert_eq!(Some(12), Card::new(13, Spade).to_index());
+        assert_eq!(Some(13), Card::new(1, Heart).to_index());
+        assert_eq!(Some(51), Card::new(13, Club).to_index());
+        assert_eq!(Some(52), Card::BlackJoker.to_index());
+        assert_eq!(Some(53), Card::WhiteJoker.to_index());
+    }
+
+    #[test]
+    fn invalid_number() {
+        assert_eq!(Ok(Card::new(0, Spade)), "0S".parse());
+        assert_eq!(None, Card::new(0, Spade).to_index());
+        assert_eq!(None, Card::new(14, Club).to_index());
+        assert_eq!(None, Card::new(14, Club).to_mask());
+    }
 }
@@ -0,0 +1,141 @@
+//! Orderings of card ranks.
+//!
+//! The derived `Ord` of `SuitCard` compares numbers first (aces are low), and
+//! then suits. The comparators in this module compare only ranks, in the
+//! order used by each game.
+
+use crate::SuitCard;
+use std::cmp::Ordering;
+
+/// Ordering of card ranks.
+pub trait RankOrder {
+    /// Returns the strength of the rank `num`. Larger is stronger.
+    fn value(&self, num: u8) -> u8;
+
+    /// Compares the ranks of two cards, ignoring their suits.
+    fn compare(&self, c0: &SuitCard, c1: &SuitCard) -> Ordering {
+        self.value(c0.num).cmp(&self.value(c1.num))
+    }
+}
+
+/// Ordering in which aces are higher than kings.
+///
+/// ```rust
+/// use playing_card::order::{AceHigh, RankOrder};
+/// use std::cmp::Ordering;
+///
+/// let ace = "AS".parse().unwrap();
+/// let king = "KS".parse().unwrap();
+/// assert_eq!(Ordering::Greater, AceHigh.compare(&ace, &king));
+/// ```
+#[derive(Eq, PartialEq, Copy, Clone, Debug, Default)]
+pub struct AceHigh;
+
+impl RankOrder for AceHigh {
+    fn value(&self, num: u8) -> u8 {
+        if num == 1 {
+            14
+        } else {
+            num
+        }
+    }
+}
+
+/// Ordering in which aces are lower than twos.
+#[derive(Eq, PartialEq, Copy, Clone, Debug, Default)]
+pub struct AceLow;
+
+impl RankOrder for AceLow {
+    fn value(&self, num: u8) -> u8 {
+        num
+    }
+}
+
+/// Game-specific ordering given by a list of ranks.
+///
+/// ```rust
+/// use playing_card::order::{CustomOrder, RankOrder};
+/// use std::cmp::Ordering;
+///
+/// // Big Two: threes are the lowest, twos are the highest.
+/// let order = CustomOrder::new(&[3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 1, 2]);
+/// let two = "2S".parse().unwrap();
+/// let ace = "AS".parse().unwrap();
+/// assert_eq!(Ordering::Greater, order.compare(&two, &ace));
+/// ```
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+pub struct CustomOrder {
+    values: [u8; 14],
+}
+
+impl CustomOrder {
+    /// Creates a new ordering from the ranks listed from the lowest to the highest.
+    ///
+    /// Ranks not in the list are lower than any listed rank. Ranks out of
+    /// `1..=13` are ignored.
+    pub fn new(nums: &[u8]) -> CustomOrder {
+        let mut values = [0; 14];
+        for (i, &n) in nums.iter().enumerate() {
+            if let Some(v) = values.get_mut(n as usize) {
+                *v = i as u8 + 1;
+            }
+        }
+        CustomOrder { values }
+    }
+}
+
+impl RankOrder for CustomOrder {
+    fn value(&self, num: u8) -> u8 {
+        self.values.get(num as usize).cloned().unwrap_or(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AceHigh, AceLow, CustomOrder, RankOrder};
+    use crate::SuitCard;
+    use std::cmp::Ordering;
+
+    fn check<O: RankOrder>(order: &O, expected: &str) {
+        let mut cards = "KD QD JD TD 9D 8D 7D 6D 5D 4D 3D 2D AD"
+            .split(' ')
+            .map(|s| s.parse().unwrap())
+            .collect::<Vec<SuitCard>>();
+        cards.sort_by(|c0, c1| order.compare(c0, c1));
+        let actual = cards.iter().map(|c| c.to_string()).collect::<Vec<_>>();
+        assert_eq!(expected, actual.join(" "));
+    }
+
+    #[test]
+    fn sort() {
+        check(&AceHigh, "2D 3D 4D 5D 6D 7D 8D 9D TD JD QD KD AD");
+        check(&AceLow, "AD 2D 3D 4D 5D 6D 7D 8D 9D TD JD QD KD");
+        check(
+            &CustomOrder::new(&[3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 1, 2]),
+            "3D 4D 5D 6D 7D 8D 9D TD JD QD KD AD 2D",
+        );
+    }
+
+    #[test]
+    fn ignore_suit() {
+        let c0 = "AS".parse().unwrap();
+        let c1 = "AC".parse().unwrap();
+        assert_eq!(Ordering::Equal, AceHigh.compare(&c0, &c1));
+        assert_eq!(Ordering::Equal, AceLow.compare(&c0, &c1));
+        assert_eq!(Ordering::Less, c0.cmp(&c1));
+    }
+
+    #[test]
+    fn unlisted() {
+        let order = CustomOrder::new(&[10, 11, 12, 13, 1]);
+        assert_eq!(0, order.value(2));
+        assert_eq!(0, order.value(9));
+        assert_eq!(1, order.value(10));
+        assert_eq!(5, order.value(1));
+        assert_eq!(0, order.value(14));
+        assert_eq!(0, order.value(u8::MAX));
+
+        let order = CustomOrder::new(&[2, 14, 1]);
+        assert_eq!(3, order.value(1));
+    }
+}
@@ -0,0 +1,578 @@
+//! Poker hand evaluator.
+
+use crate::{
+    order::{AceHigh, RankOrder},
+    SuitCard,
+};
+use std::{cmp::Ordering, error::Error, fmt, str::FromStr};
+
+fn cmp_card(c0: &SuitCard, c1: &SuitCard) -> Ordering {
+    AceHigh.compare(c0, c1)
+}
+
+fn cmp_card_array(a0: &[SuitCard], a1: &[SuitCard]) -> Ordering {
+    assert_eq!(a0.len(), a1.len());
+    for (c0, c1) in a0.iter().zip(a1.iter()) {
+        let ord = cmp_card(c0, c1);
+        if ord != Ordering::Equal {
+            return ord;
+        }
+    }
+    Ordering::Equal
+}
+
+fn cmp_card_2darray(as0: &[&[SuitCard]], as1: &[&[SuitCard]]) -> Ordering {
+    assert_eq!(as0.len(), as1.len());
+    for (&a0, &a1) in as0.iter().zip(as1.iter()) {
+        let ord = cmp_card_array(a0, a1);
+        if ord != Ordering::Equal {
+            return ord;
+        }
+    }
+    Ordering::Equal
+}
+
+fn sort_cards(cs: &mut [SuitCard]) {
+    cs.sort_by(|c0, c1| match cmp_card(c0, c1) {
+        Ordering::Equal => (c0.suit as u32).cmp(&(c1.suit as u32)),
+        Ordering::Less => Ordering::Greater,
+        Ordering::Greater => Ordering::Less,
+    })
+}
+
+/// Parses space-separated cards such as `"8C TS KC 9H 4S"`.
+#[allow(clippy::result_unit_err)]
+pub fn parse_cards(s: &str) -> Result<Vec<SuitCard>, ()> {
+    s.split_whitespace().map(FromStr::from_str).collect()
+}
+
+/// Why cards cannot be evaluated as a poker hand.
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+pub enum HandError {
+    /// The number of the cards is not allowed, such as 4 cards for `from_cards`.
+    WrongNumberOfCards(usize),
+    /// The number of the card is not in `1..=13`.
+    InvalidCard(SuitCard),
+    /// The card is given more than once, making five of a kind.
+    DuplicatedCard(SuitCard),
+}
+
+impl fmt::Display for HandError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            HandError::WrongNumberOfCards(n) => write!(f, "{} cards cannot make a hand", n),
+            HandError::InvalidCard(c) => write!(f, "{} has an invalid number", c),
+            HandError::DuplicatedCard(c) => write!(f, "{} is used twice", c),
+        }
+    }
+}
+
+impl Error for HandError {}
+
+type C1 = [SuitCard; 1];
+type C2 = [SuitCard; 2];
+type C3 = [SuitCard; 3];
+type C4 = [SuitCard; 4];
+type C5 = [SuitCard; 5];
+
+/// Category of a poker hand, from the weakest to the strongest.
+#[allow(missing_docs)]
+#[derive(Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Debug, Hash)]
+pub enum HandRank {
+    HighCard,
+    Pair,
+    TwoPairs,
+    ThreeOfAKind,
+    Straight,
+    Flush,
+    FullHouse,
+    FourOfAKind,
+    StraightFlush,
+    RoyalFlush,
+}
+
+/// Five-card poker hand.
+///
+/// The cards of each group are stored in the order they are compared on a tie,
+/// so that two hands of the same rank are ordered by their first differing card.
+/// Aces rank high, except in the ace-low straight (A-2-3-4-5), whose ace is
+/// stored last.
+#[allow(missing_docs)]
+#[derive(Eq, Copy, Clone, Debug)]
+pub enum Hand {
+    HighCard(C1, C1, C1, C1, C1),
+    Pair(C2, C1, C1, C1),
+    TwoPairs(C2, C2, C1),
+    ThreeOfAKind(C3, C1, C1),
+    Straight(C5),
+    Flush(C5),
+    FullHouse(C3, C2),
+    FourOfAKind(C4, C1),
+    StraightFlush(C5),
+    RoyalFlush(C5),
+}
+
+impl fmt::Display for Hand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Hand::HighCard([c0], [c1], [c2], [c3], [c4]) => {
+                write!(f, "HighCard({}, {}, {}, {}, {})", c0, c1, c2, c3, c4)
+            }
+            Hand::Pair([c0, c1], [c2], [c3], [c4]) => {
+                write!(f, "Pair({}, {}) + HighCard({}, {}, {})", c0, c1, c2, c3, c4)
+            }
+            Hand::TwoPairs([c0, c1], [c2, c3], [c4]) => write!(
+                f,
+                "TwoPairs(({}, {}), ({}, {})) + HighCard({})",
+                c0, c1, c2, c3, c4
+            ),
+            Hand::ThreeOfAKind([c0, c1, c2], [c3], [c4]) => write!(
+                f,
+                "ThreeOfAKind({}, {}, {}) + HighCard({}, {})",
+                c0, c1, c2, c3, c4
+            ),
+            Hand::Straight([c0, c1, c2, c3, c4]) => {
+                write!(f, "Straight({}, {}, {}, {}, {})", c0, c1, c2, c3, c4)
+            }
+            Hand::Flush([c0, c1, c2, c3, c4]) => {
+                write!(f, "Flush({}, {}, {}, {}, {})", c0, c1, c2, c3, c4)
+            }
+            Hand::FullHouse([c0, c1, c2], [c3, c4]) => {
+                write!(f, "FullHouse(({}, {}, {}), ({}, {}))", c0, c1, c2, c3, c4)
+            }
+            Hand::FourOfAKind([c0, c1, c2, c3], [c4]) => write!(
+                f,
+                "FourOfAKind({}, {}, {}, {}) + HighCard({})",
+                c0, c1, c2, c3, c4
+            ),
+            Hand::StraightFlush([c0, c1, c2, c3, c4]) => {
+                write!(f, "StraightFlush({}, {}, {}, {}, {})", c0, c1, c2, c3, c4)
+            }
+            Hand::RoyalFlush([c0, c1, c2, c3, c4]) => {
+                write!(f, "RoyalFlush({}, {}, {}, {}, {})", c0, c1, c2, c3, c4)
+            }
+        }
+    }
+}
+
+impl PartialEq for Hand {
+    fn eq(&self, other: &Hand) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Hand) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Hand {
+    fn cmp(&self, other: &Hand) -> Ordering {
+        match self.rank().cmp(&other.rank()) {
+            Ordering::Less => Ordering::Less,
+            Ordering::Greater => Ordering::Greater,
+            Ordering::Equal => cmp_card_2darray(&self.groups(), &other.groups()),
+        }
+    }
+}
+
+impl FromStr for Hand {
+    type Err = ();
+
+    /// Parses five or more cards and evaluates the best five of them.
+    fn from_str(s: &str) -> Result<Hand, ()> {
+        let cards = parse_cards(s)?;
+        Hand::best_of(&cards).map_err(|_| ())
+    }
+}
+
+impl Hand {
+    fn pair(mut p0: C2, s0: SuitCard, s1: SuitCard, s2: SuitCard) -> Hand {
+        sort_cards(&mut p0);
+        let mut ss = [s0, s1, s2];
+        sort_cards(&mut ss);
+        Hand::Pair(p0, [ss[0]], [ss[1]], [ss[2]])
+    }
+
+    fn two_pair(mut p0: C2, mut p1: C2, s0: SuitCard) -> Hand {
+        sort_cards(&mut p0);
+        sort_cards(&mut p1);
+        match cmp_card(&p0[0], &p1[0]) {
+            Ordering::Less => Hand::TwoPairs(p1, p0, [s0]),
+            Ordering::Greater => Hand::TwoPairs(p0, p1, [s0]),
+            Ordering::Equal => panic!(),
+        }
+    }
+
+    fn three_of_a_kind(mut t0: C3, s0: SuitCard, s1: SuitCard) -> Hand {
+        sort_cards(&mut t0);
+        let mut ss = [s0, s1];
+        sort_cards(&mut ss);
+        Hand::ThreeOfAKind(t0, [ss[0]], [ss[1]])
+    }
+
+    fn full_house(mut t0: C3, mut p0: C2) -> Hand {
+        sort_cards(&mut t0);
+        sort_cards(&mut p0);
+        Hand::FullHouse(t0, p0)
+    }
+
+    fn four_of_a_kind(mut q0: C4, s0: SuitCard) -> Hand {
+        sort_cards(&mut q0);
+        Hand::FourOfAKind(q0, [s0])
+    }
+
+    /// Evaluates exactly five cards.
+    ///
+    /// Fails if not five cards are given, a card has a number out of `1..=13`
+    /// or the cards are five of a kind.
+    ///
+    /// ```rust
+    /// use playing_card::poker::{parse_cards, Hand, HandRank};
+    ///
+    /// let hand = Hand::from_cards(&parse_cards("8C TS KC 9H 4S").unwrap()).unwrap();
+    /// assert_eq!(HandRank::HighCard, hand.rank());
+    /// let hand = Hand::from_cards(&parse_cards("AD 2C 3H 4S 5D").unwrap()).unwrap();
+    /// assert_eq!(HandRank::Straight, hand.rank());
+    /// ```
+    pub fn from_cards(cards: &[SuitCard]) -> Result<Hand, HandError> {
+        if cards.len() != 5 {
+            return Err(HandError::WrongNumberOfCards(cards.len()));
+        }
+        if let Some(&c) = cards.iter().find(|c| c.num < 1 || c.num > 13) {
+            return Err(HandError::InvalidCard(c));
+        }
+
+        let mut num_count = (0..13).map(|_| vec![]).collect::<Vec<_>>();
+        let mut suit_count = (0..4).map(|_| vec![]).collect::<Vec<_>>();
+
+        for &c in cards {
+            let val = if c.num == 1 { 12 } else { c.num - 2 };
+            num_count[(12 - val) as usize].push(c);
+            suit_count[c.suit as usize].push(c);
+        }
+
+        let num_count = num_count;
+        let suit_count = suit_count;
+
+        let mut single = vec![];
+        let mut pairs = vec![];
+        let mut three = vec![];
+        let mut four = vec![];
+        for v in &num_count {
+            match v.len() {
+                0 => {
+                    // Do nothing
+                }
+                1 => single.push(v[0]),
+                2 => pairs.push([v[0], v[1]]),
+                3 => three.push([v[0], v[1], v[2]]),
+                4 => four.push([v[0], v[1], v[2], v[3]]),
+                _ => return Err(HandError::DuplicatedCard(v[4])),
+            }
+        }
+
+        assert_eq!(
+            5,
+            single.len() + pairs.len() * 2 + three.len() * 3 + four.len() * 4
+        );
+
+        match (pairs.len(), three.len(), four.len()) {
+            (1, 0, 0) => return Ok(Hand::pair(pairs[0], single[0], single[1], single[2])),
+            (2, 0, 0) => return Ok(Hand::two_pair(pairs[0], pairs[1], single[0])),
+            (0, 1, 0) => return Ok(Hand::three_of_a_kind(three[0], single[0], single[1])),
+            (1, 1, 0) => return Ok(Hand::full_house(three[0], pairs[0])),
+            (0, 0, 1) => return Ok(Hand::four_of_a_kind(four[0], single[0])),
+            _ => {
+                // Do nothing
+            }
+        }
+
+        let is_flush = suit_count.iter().any(|v| v.len() == 5);
+        let mut is_straight = {
+            let min_idx = num_count.iter().position(|v| !v.is_empty()).unwrap();
+            num_count[min_idx..(min_idx + 5)]
+                .iter()
+                .all(|v| v.len() == 1)
+        };
+
+        let mut ss = [single[0], single[1], single[2], single[3], single[4]];
+        sort_cards(&mut ss);
+        if ss[0].num == 1 && ss[1].num == 5 && ss[2].num == 4 && ss[3].num == 3 && ss[4].num == 2 {
+            ss = [ss[1], ss[2], ss[3], ss[4], ss[0]];
+            is_straight = true;
+        }
+
+        Ok(match (is_flush, is_straight) {
+            (true, true) if ss[0].num == 1 => Hand::RoyalFlush(ss),
+            (true, true) => Hand::StraightFlush(ss),
+            (true, false) => Hand::Flush(ss),
+            (false, true) => Hand::Straight(ss),
+            (false, false) => Hand::HighCard([ss[0]], [ss[1]], [ss[2]], [ss[3]], [ss[4]]),
+        })
+    }
+
+    /// Evaluates the best five-card hand that can be made from five or more cards.
+    ///
+    /// Fails as `from_cards` does, or if fewer than five cards are given.
+    ///
+    /// ```rust
+    /// use playing_card::poker::{parse_cards, Hand, HandRank};
+    ///
+    /// // Texas hold'em: two hole cards and five community cards.
+    /// let hand = Hand::best_of(&parse_cards("AH KH 2C QH 7D JH TH").unwrap()).unwrap();
+    /// assert_eq!(HandRank::RoyalFlush, hand.rank());
+    /// ```
+    pub fn best_of(cards: &[SuitCard]) -> Result<Hand, HandError> {
+        if cards.len() < 5 {
+            return Err(HandError::WrongNumberOfCards(cards.len()));
+        }
+
+        let n = cards.len();
+        let mut best = None;
+        for i0 in 0..n {
+            for i1 in (i0 + 1)..n {
+                for i2 in (i1 + 1)..n {
+                    for i3 in (i2 + 1)..n {
+                        for i4 in (i3 + 1)..n {
+                            let hand = Hand::from_cards(&[
+                                cards[i0], cards[i1], cards[i2], cards[i3], cards[i4],
+                            ])?;
+                            best = match best {
+                                Some(b) if b >= hand => Some(b),
+                                _ => Some(hand),
+                            };
+                        }
+                    }
+                }
+            }
+        }
+        Ok(best.unwrap())
+    }
+
+    /// Returns the category of the hand.
+    pub fn rank(&self) -> HandRank {
+        match *self {
+            Hand::HighCard(..) => HandRank::HighCard,
+            Hand::Pair(..) => HandRank::Pair,
+            Hand::TwoPairs(..) => HandRank::TwoPairs,
+            Hand::ThreeOfAKind(..) => HandRank::ThreeOfAKind,
+            Hand::Straight(..) => HandRank::Straight,
+            Hand::Flush(..) => HandRank::Flush,
+            Hand::FullHouse(..) => HandRank::FullHouse,
+            Hand::FourOfAKind(..) => HandRank::FourOfAKind,
+            Hand::StraightFlush(..) => HandRank::StraightFlush,
+            Hand::RoyalFlush(..) => HandRank::RoyalFlush,
+        }
+    }
+
+    /// Returns the five cards in tie-breaking order.
+    pub fn to_array(self) -> [SuitCard; 5] {
+        match self {
+            Hand::HighCard([c0], [c1], [c2], [c3], [c4])
+            | Hand::Pair([c0, c1], [c2], [c3], [c4])
+            | Hand::TwoPairs([c0, c1], [c2, c3], [c4])
+            | Hand::ThreeOfAKind([c0, c1, c2], [c3], [c4])
+            | Hand::Straight([c0, c1, c2, c3, c4])
+            | Hand::Flush([c0, c1, c2, c3, c4])
+            | Hand::FullHouse([c0, c1, c2], [c3, c4])
+            | Hand::FourOfAKind([c0, c1, c2, c3], [c4])
+            | Hand::StraightFlush([c0, c1, c2, c3, c4])
+            | Hand::RoyalFlush([c0, c1, c2, c3, c4]) => [c0, c1, c2, c3, c4],
+        }
+    }
+
+    fn groups(&self) -> Vec<&[SuitCard]> {
+        match *self {
+            Hand::HighCard(ref s0, ref s1, ref s2, ref s3, ref s4) => vec![s0, s1, s2, s3, s4],
+            Hand::Pair(ref p0, ref s0, ref s1, ref s2) => vec![p0, s0, s1, s2],
+            Hand::TwoPairs(ref p0, ref p1, ref s0) => vec![p0, p1, s0],
+            Hand::ThreeOfAKind(ref t0, ref s0, ref s1) => vec![t0, s0, s1],
+            Hand::Straight(ref cs) => vec![cs],
+            Hand::Flush(ref cs) => vec![cs],
+            Hand::FullHouse(ref t0, ref p0) => vec![t0, p0],
+            Hand::FourOfAKind(ref q0, ref s0) => vec![q0, s0],
+            Hand::StraightFlush(ref cs) => vec![cs],
+            Hand::RoyalFlush(ref cs) => vec![cs],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_cards, Hand, HandError, HandRank};
+    use rand::{self, seq::SliceRandom};
+    use std::cmp::Ordering;
+
+    fn str_to_cards(s: &str) -> Vec<crate::SuitCard> {
+        parse_cards(s).unwrap()
+    }
+
+    #[test]
+    fn from_cards() {
+        fn check(input: &str, output: &str) {
+            let mut cs = str_to_cards(input);
+            let ihand = Hand::from_cards(&cs).unwrap();
+            assert_eq!(output, &ihand.to_string()[..]);
+
+            let mut rng = rand::thread_rng();
+            for _ in 0..10 {
+                cs.shuffle(&mut rng);
+                let hand = Hand::from_cards(&cs).unwrap();
+                assert_eq!(ihand.to_array(), hand.to_array());
+                assert_eq!(output, &hand.to_string()[..]);
+            }
+        }
+
+        check("AC JS 9S 8C 5D", "HighCard(AC, JS, 9S, 8C, 5D)");
+        check("QH 8S 7D 5C 2C", "HighCard(QH, 8S, 7D, 5C, 2C)");
+
+        check("3D 3C AD JS 4H", "Pair(3D, 3C) + HighCard(AD, JS, 4H)");
+        check("5H 5C KD 7S 6S", "Pair(5H, 5C) + HighCard(KD, 7S, 6S)");
+        check("QH QC 9H 6S 4D", "Pair(QH, QC) + HighCard(9H, 6S, 4D)");
+        check("QS QD 7H 6D 3D", "Pair(QS, QD) + HighCard(7H, 6D, 3D)");
+
+        check(
+            "QH QC 2H 2D 7C",
+            "TwoPairs((QH, QC), (2H, 2D)) + HighCard(7C)",
+        );
+
+        check(
+            "9S 9H 9D QS 7C",
+            "ThreeOfAKind(9S, 9H, 9D) + HighCard(QS, 7C)",
+        );
+
+        check("9C 8H 7D 6D 5S", "Straight(9C, 8H, 7D, 6D, 5S)");
+        check("AC 2H 3D 4D 5S", "Straight(5S, 4D, 3D, 2H, AC)");
+
+        check("KC TC 8C 5C 2C", "Flush(KC, TC, 8C, 5C, 2C)");
+
+        check("6S 6H 6C 3D 3C", "FullHouse((6S, 6H, 6C), (3D, 3C))");
+        check("4S 4D 4C 2H 2D", "FullHouse((4S, 4D, 4C), (2H, 2D))");
+        check("3S 3D 3C 9S 9D", "FullHouse((3S, 3D, 3C), (9S, 9D))");
+
+        check(
+            "8S 8H 8D 8C 2D",
+            "FourOfAKind(8S, 8H, 8D, 8C) + HighCard(2D)",
+        );
+        check("7H 6H 5H 4H 3H", "StraightFlush(7H, 6H, 5H, 4H, 3H)");
+        check("AH 2H 3H 4H 5H", "StraightFlush(5H, 4H, 3H, 2H, AH)");
+
+        check("AS KS QS JS TS", "RoyalFlush(AS, KS, QS, JS, TS)");
+    }
+
+    #[test]
+    fn cmp() {
+        fn check(order: Ordering, left: &str, right: &str) {
+            let lh = Hand::from_cards(&str_to_cards(left)).unwrap();
+            let rh = Hand::from_cards(&str_to_cards(right)).unwrap();
+            assert_eq!(order, lh.cmp(&rh));
+            assert_eq!(order.reverse(), rh.cmp(&lh));
+        }
+
+        check(Ordering::Greater, "5D 8C 9S JS AC", "2C 5C 7D 8S QH");
+        check(Ordering::Greater, "AD TD 9S 5C 4C", "KS QD JC 8H 7H");
+        check(Ordering::Greater, "AC QC 7D 5H 2C", "AD TD 9S 5C 4C");
+
+        check(Ordering::Less, "5H 5C 6S 7S KD", "2C 3S 8S 8D TD");
+        check(Ordering::Greater, "4D 6S 9H QH QC", "3D 6D 7H QD QS");
+        check(Ordering::Greater, "TC TS 6S 4H 2H", "9H 9C AH QD TD");
+        check(Ordering::Greater, "2D 2H 8S 5C 4C", "2C 2S 8C 5H 3H");
+
+        check(Ordering::Greater, "KH KD 2C 2D JH", "JD JS TS TC 9S");
+        check(Ordering::Greater, "9C 9D 7D 7S 6H", "9H 9S 5H 5D KC");
+        check(Ordering::Greater, "4S 4C 3S 3H KD", "4H 4D 3D 3C TS");
+
+        check(Ordering::Greater, "QS QC QD 5S 3C", "5C 5H 5D QD TC");
+        check(Ordering::Greater, "8C 8H 8C AC 2D", "8S 8H 8D 5S 3C");
+
+        check(Ordering::Greater, "8S 7S 6H 5H 4S", "6D 5S 4D 3H 2C");
+        check(Ordering::Equal, "8S 7S 6H 5H 4S", "8H 7D 6C 5C 4H");
+        check(Ordering::Greater, "6D 5S 4D 3H 2C", "AD 2S 3D 4H 5C");
+        check(Ordering::Greater, "AD 2S 3D 4H 5C", "AH AC AS KD QD");
+
+        check(Ordering::Greater, "AH QH TH 5H 3H", "KS QS JS 9S 6S");
+        check(Ordering::Greater, "AD KD 7D 6D 2D", "AH QH TH 5H 3H");
+
+        check(Ordering::Greater, "2H 2D 4C 4D 4S", "3C 3D 3S 9S 9D");
+        check(Ordering::Greater, "TS TH TD 4S 4D", "9H 9C 9S AH AC");
+        check(Ordering::Greater, "AS AC AH 4D 4C", "AS AH AD 3S 3D");
+
+        check(Ordering::Greater, "TC TD TH TS 5D", "6D 6H 6S 6C KS");
+        check(Ordering::Greater, "TC TD TH TS QC", "TC TD TH TS 5D");
+
+        check(Ordering::Less, "7C 6C 5C 4C 3C", "AH KH QH JH TH");
+        check(Ordering::Greater, "7H 6H 5H 4H 3H", "5S 4S 3S 2S 1S");
+        check(Ordering::Equal, "JC TC 9C 8C 7C", "JD TD 9D 8D 7D");
+
+        check(Ordering::Less, "2D 9C AS AH AC", "3D 6D 7D TD QD");
+        check(Ordering::Greater, "5S 4S 3S 2S 1S", "TC TH TD TS 3H");
+    }
+
+    #[test]
+    fn best_of() {
+        fn check(input: &str, output: &str) {
+            let mut cs = str_to_cards(input);
+            let mut rng = rand::thread_rng();
+            for _ in 0..10 {
+                cs.shuffle(&mut rng);
+                assert_eq!(output, &Hand::best_of(&cs).unwrap().to_string()[..]);
+            }
+        }
+
+        check("AC JS 9S 8C 5D", "HighCard(AC, JS, 9S, 8C, 5D)");
+        check("AC JS 9S 8C 5D 3H 2H", "HighCard(AC, JS, 9S, 8C, 5D)");
+        check(
+            "AC AS 9S 9C 5D 4H 2H",
+            "TwoPairs((AS, AC), (9S, 9C)) + HighCard(5D)",
+        );
+        check("KC KS KH 9C 9D 8H 2H", "FullHouse((KS, KH, KC), (9D, 9C))");
+        check("AC 2H 3D 4D 5S KH KD", "Straight(5S, 4D, 3D, 2H, AC)");
+        check("AC 2H 3D 4D 5S 6H KD", "Straight(6H, 5S, 4D, 3D, 2H)");
+        check("2C 3C 4C 5C 6C 7C 8C", "StraightFlush(8C, 7C, 6C, 5C, 4C)");
+    }
+
+    #[test]
+    fn parse() {
+        let hand = "8C TS KC 9H 4S".parse::<Hand>().unwrap();
+        assert_eq!(HandRank::HighCard, hand.rank());
+        assert_eq!("HighCard(KC, TS, 9H, 8C, 4S)", &hand.to_string()[..]);
+
+        let hand = "AH 2C 3S 4D 5H 5S 5C".parse::<Hand>().unwrap();
+        assert_eq!(HandRank::Straight, hand.rank());
+
+        assert_eq!(Err(()), "8C TS KC 9H".parse::<Hand>());
+        assert_eq!(Err(()), "8C TS KC 9H XX".parse::<Hand>());
+        assert_eq!(Ok(vec![]), parse_cards(""));
+    }
+
+    #[test]
+    fn invalid() {
+        let cs = str_to_cards("8C TS KC 9H 4S 3D");
+        assert_eq!(Err(HandError::WrongNumberOfCards(6)), Hand::from_cards(&cs));
+        assert_eq!(
+            Err(HandError::WrongNumberOfCards(4)),
+            Hand::best_of(&cs[..4])
+        );
+
+        let mut cs = str_to_cards("8C TS KC 9H 4S");
+        cs[2].num = 14;
+        assert_eq!(Err(HandError::InvalidCard(cs[2])), Hand::from_cards(&cs));
+        cs[2].num = 0;
+        assert_eq!(Err(HandError::InvalidCard(cs[2])), Hand::best_of(&cs));
+
+        let cs = str_to_cards("8C 8H 8D 8S 8C");
+        assert!(matches!(
+            Hand::from_cards(&cs),
+            Err(HandError::DuplicatedCard(_))
+        ));
+    }
+
+    #[test]
+    fn rank_order() {
+        assert!(HandRank::HighCard < HandRank::Pair);
+        assert!(HandRank::Straight < HandRank::Flush);
+        assert!(HandRank::StraightFlush < HandRank::RoyalFlush);
+    }
+}
@@ -0,0 +1,170 @@
+//! Salted hashes of answers, which keep the answers out of the sources.
+//!
+//! A hashed answer is written as `sha256:SALT:DIGEST`, where `DIGEST` is the
+//! hexadecimal SHA-256 digest of `SALT` followed by the answer. `problem!`
+//! accepts it in place of the plaintext answer.
+
+use crate::{data::sha256, Result};
+use failure::bail;
+use std::{env, process, time::SystemTime};
+
+/// Environment variable which makes solvers print the answers of problems whose
+/// expected answers are hashed. Set by `--reveal`.
+pub const REVEAL_ENV: &str = "EULER_REVEAL";
+
+const PREFIX: &str = "sha256:";
+
+/// Returns `true` if `expected` is a hashed answer.
+pub fn is_hashed(expected: &str) -> bool {
+    expected.starts_with(PREFIX)
+}
+
+/// Hashes `answer` with `salt`.
+pub fn hash(salt: &str, answer: &str) -> String {
+    let digest = sha256(format!("{}{}", salt, answer).as_bytes());
+    format!("{}{}:{}", PREFIX, salt, digest)
+}
+
+/// Generates a new salt. Salts only need to differ between problems.
+pub fn new_salt(seed: &str) -> String {
+    let nsec = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map(|d| d.as_nanos())
+        .unwrap_or(0);
+    let digest = sha256(format!("{}:{}:{}", seed, process::id(), nsec).as_bytes());
+    digest[..16].to_string()
+}
+
+fn salt(expected: &str) -> Option<&str> {
+    let rest = expected.strip_prefix(PREFIX)?;
+    rest.find(':').map(|idx| &rest[..idx])
+}
+
+/// Returns `true` if `answer` is the `expected` one, which may be hashed.
+pub fn check(expected: &str, answer: &str) -> bool {
+    match salt(expected) {
+        Some(salt) => hash(salt, answer) == expected,
+        None => answer == expected,
+    }
+}
+
+/// Hashes `answer` with the salt of `expected` if `expected` is hashed and
+/// answers are not revealed.
+pub fn conceal(expected: &str, answer: String) -> String {
+    match salt(expected) {
+        Some(salt) if !is_revealed() => hash(salt, &answer),
+        _ => answer,
+    }
+}
+
+/// Returns `true` if the answers of problems with hashed answers are printed.
+pub fn is_revealed() -> bool {
+    env::var_os(REVEAL_ENV).is_some()
+}
+
+/// Makes the answers of problems with hashed answers printed, in this process
+/// and its child processes.
+pub fn reveal() {
+    env::set_var(REVEAL_ENV, "1");
+}
+
+// Returns the length of the `key: value, ...;` metadata at the start of the
+// arguments of `problem!`, including the semicolon, or 0 if there is none.
+fn metadata_len(args: &str) -> Result<usize> {
+    if args.trim_start().starts_with('"') {
+        return Ok(0);
+    }
+    let mut in_string = false;
+    let mut is_escaped = false;
+    for (idx, c) in args.char_indices() {
+        match c {
+            _ if is_escaped => is_escaped = false,
+            '\\' if in_string => is_escaped = true,
+            '"' => in_string = !in_string,
+            ';' if !in_string => return Ok(idx + 1),
+            _ => {}
+        }
+    }
+    bail!("unterminated problem! metadata")
+}
+
+/// Replaces the plaintext answer of the `problem!` invocation in `source` with
+/// its hash. Returns `None` if the answer is already hashed.
+pub fn hash_source(source: &str, salt: &str) -> Result<Option<String>> {
+    const MACRO: &str = "problem!(";
+
+    let args = match source.find(MACRO) {
+        Some(idx) => idx + MACRO.len(),
+        None => bail!("no problem! invocation found"),
+    };
+    let rest = &source[args..];
+    let rest = rest[metadata_len(rest)?..].trim_start();
+    let start = match rest.strip_prefix('"') {
+        Some(answer) => source.len() - answer.len(),
+        None => bail!("no answer literal found"),
+    };
+    let len = match source[start..].find('"') {
+        Some(len) => len,
+        None => bail!("unterminated answer literal"),
+    };
+    let answer = &source[start..start + len];
+    if answer.contains('\\') {
+        bail!("answer literal with escapes is not supported: {}", answer);
+    }
+    if is_hashed(answer) {
+        return Ok(None);
+    }
+
+    let mut hashed = source[..start].to_string();
+    hashed.push_str(&hash(salt, answer));
+    hashed.push_str(&source[start + len..]);
+    Ok(Some(hashed))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{check, hash, hash_source, is_hashed};
+
+    #[test]
+    fn hashed_answer() {
+        let expected = hash("0123abcd", "233168");
+        assert!(is_hashed(&expected));
+        assert!(expected.starts_with("sha256:0123abcd:"));
+        assert!(check(&expected, "233168"));
+        assert!(!check(&expected, "233169"));
+        assert!(check("233168", "233168"));
+        assert!(!check("233168", &expected));
+    }
+
+    #[test]
+    fn source() {
+        let source = "fn main() {}\ncommon::problem!(\"233168\", solve);\n";
+        let hashed = hash_source(source, "salt").unwrap().unwrap();
+        assert_eq!(
+            format!(
+                "fn main() {{}}\ncommon::problem!(\"{}\", solve);\n",
+                hash("salt", "233168")
+            ),
+            hashed
+        );
+        assert_eq!(None, hash_source(&hashed, "salt").unwrap());
+        assert!(hash_source("fn main() {}", "salt").is_err());
+    }
+
+    #[test]
+    fn source_with_metadata() {
+        let source = "common::problem!(
+    title: \"Multiples of 3; and \\\"5\\\"\",
+    tags: &[\"arithmetic\"];
+    \"233168\", solve
+);
+";
+        let hashed = hash_source(source, "salt").unwrap().unwrap();
+        assert_eq!(
+            source.replace("\"233168\"", &format!("\"{}\"", hash("salt", "233168"))),
+            hashed
+        );
+        assert_eq!(None, hash_source(&hashed, "salt").unwrap());
+        assert!(hash_source("common::problem!(title: \"x\"", "salt").is_err());
+    }
+}
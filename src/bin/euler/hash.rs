@@ -0,0 +1,62 @@
+//! `euler hash-answers` subcommand.
+
+use crate::Result;
+use common::answer;
+use failure::bail;
+use getopts::Options;
+use std::{fs, path::Path};
+
+const SOURCE_PAT: &str = "src/bin/p[0-9][0-9][0-9].rs";
+
+/// Replaces the plaintext answers in the sources of the problems with salted
+/// hashes. Converts all problems in `src/bin` if no file is given.
+pub fn run(program: &str, args: &[String]) -> Result<bool> {
+    let mut opts = Options::new();
+    let _ = opts.optflag(
+        "n",
+        "dry-run",
+        "Print the files to convert without modifying them",
+    );
+    let _ = opts.optflag("h", "help", "Display this message");
+
+    let matches = opts.parse(args)?;
+    if matches.opt_present("h") {
+        let short = format!("{} hash-answers [options] [FILES...]", program);
+        println!("{}", opts.usage(&short));
+        return Ok(true);
+    }
+    let dry_run = matches.opt_present("n");
+
+    let mut paths = vec![];
+    if matches.free.is_empty() {
+        for path in glob::glob(SOURCE_PAT)? {
+            paths.push(path?);
+        }
+        if paths.is_empty() {
+            bail!("no problem found at {}", SOURCE_PAT);
+        }
+    } else {
+        paths.extend(matches.free.iter().map(|s| Path::new(s).to_path_buf()));
+    }
+
+    let mut is_ok = true;
+    for path in &paths {
+        let source = fs::read_to_string(path)?;
+        let salt = answer::new_salt(&path.to_string_lossy());
+        match answer::hash_source(&source, &salt) {
+            Ok(Some(hashed)) => {
+                if !dry_run {
+                    fs::write(path, hashed)?;
+                }
+                println!("{:<24} HASHED", path.display());
+            }
+            Ok(None) => println!("{:<24} ALREADY HASHED", path.display()),
+            Err(e) => {
+                println!("{:<24} ERROR: {}", path.display(), e);
+                is_ok = false;
+            }
+        }
+    }
+
+    Ok(is_ok)
+}
@@ -0,0 +1,357 @@
+//! `euler list` subcommand.
+
+use crate::{parse_selector, problem_number, registry, Result};
+use common::metadata::{self, Metadata};
+use failure::bail;
+use getopts::{Matches, Options};
+use serde::Serialize;
+use std::{collections::BTreeMap, io, io::prelude::*, str::FromStr};
+
+const NO_GROUP: &str = "(none)";
+
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+enum GroupBy {
+    Tag,
+    Crate,
+    Difficulty,
+}
+
+impl FromStr for GroupBy {
+    type Err = ();
+
+    fn from_str(s: &str) -> std::result::Result<GroupBy, ()> {
+        match s {
+            "tag" => Ok(GroupBy::Tag),
+            "crate" => Ok(GroupBy::Crate),
+            "difficulty" => Ok(GroupBy::Difficulty),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+enum Format {
+    Text,
+    Markdown,
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = ();
+
+    fn from_str(s: &str) -> std::result::Result<Format, ()> {
+        match s {
+            "text" => Ok(Format::Text),
+            "markdown" | "md" => Ok(Format::Markdown),
+            "json" => Ok(Format::Json),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Problems to include, selected by the tags and crates in their metadata.
+#[derive(Clone, Debug, Default)]
+pub struct Filter {
+    tags: Vec<String>,
+    crates: Vec<String>,
+}
+
+impl Filter {
+    /// Adds `--tag` and `--crate` to the options.
+    pub fn add_options(opts: &mut Options) {
+        let _ = opts.optmulti(
+            "",
+            "tag",
+            "Select only the problems tagged TAG. May be given several times",
+            "TAG",
+        );
+        let _ = opts.optmulti(
+            "",
+            "crate",
+            "Select only the problems using the crate NAME. May be given several times",
+            "NAME",
+        );
+    }
+
+    pub fn from_matches(matches: &Matches) -> Filter {
+        Filter {
+            tags: matches.opt_strs("tag"),
+            crates: matches.opt_strs("crate"),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tags.is_empty() && self.crates.is_empty()
+    }
+
+    /// Returns `true` if the problem has all tags and uses all crates of the filter.
+    pub fn matches(&self, metadata: &Metadata) -> bool {
+        self.tags.iter().all(|t| metadata.has_tag(t))
+            && self.crates.iter().all(|c| metadata.uses_crate(c))
+    }
+}
+
+#[derive(Serialize)]
+struct Item {
+    program: &'static str,
+    url: String,
+    #[serde(flatten)]
+    metadata: Metadata,
+}
+
+impl Item {
+    fn groups(&self, group_by: GroupBy) -> Vec<String> {
+        let groups: Vec<String> = match group_by {
+            GroupBy::Tag => self.metadata.tags.iter().map(|s| s.to_string()).collect(),
+            GroupBy::Crate => self.metadata.crates.iter().map(|s| s.to_string()).collect(),
+            GroupBy::Difficulty => self
+                .metadata
+                .difficulty
+                .iter()
+                .map(|d| format!("{}%", d))
+                .collect(),
+        };
+        if groups.is_empty() {
+            vec![NO_GROUP.to_string()]
+        } else {
+            groups
+        }
+    }
+}
+
+/// Prints the catalogue of the registered problems.
+pub fn run(program: &str, args: &[String]) -> Result<bool> {
+    let mut opts = Options::new();
+    Filter::add_options(&mut opts);
+    let _ = opts.optopt(
+        "",
+        "group-by",
+        "Group the problems by tag, crate or difficulty",
+        "KEY",
+    );
+    let _ = opts.optopt(
+        "",
+        "format",
+        "Output format: text (default), markdown or json",
+        "FORMAT",
+    );
+    let _ = opts.optflag("h", "help", "Display this message");
+
+    let matches = opts.parse(args)?;
+    if matches.opt_present("h") {
+        let short = format!("{} list [options] [PROBLEMS...]", program);
+        println!("{}", opts.usage(&short));
+        return Ok(true);
+    }
+
+    let mut ranges = vec![];
+    for s in &matches.free {
+        ranges.extend(parse_selector(s)?);
+    }
+    let filter = Filter::from_matches(&matches);
+    let group_by = match matches.opt_str("group-by") {
+        Some(s) => match s.parse() {
+            Ok(g) => Some(g),
+            Err(()) => bail!("invalid group: {}", s),
+        },
+        None => None,
+    };
+    let format = match matches.opt_str("format") {
+        Some(s) => match s.parse() {
+            Ok(f) => f,
+            Err(()) => bail!("invalid format: {}", s),
+        },
+        None => Format::Text,
+    };
+
+    let items = registry::PROBLEMS
+        .iter()
+        .filter_map(|&(program, solver)| {
+            let num = problem_number(program)?;
+            if !ranges.is_empty() && !ranges.iter().any(|&(s, e)| s <= num && num <= e) {
+                return None;
+            }
+            let metadata = solver().metadata().clone();
+            if !filter.matches(&metadata) {
+                return None;
+            }
+            Some(Item {
+                program,
+                url: metadata::url(num),
+                metadata,
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let mut out = io::stdout();
+    match group_by {
+        None => write_items(&mut out, format, &items)?,
+        Some(group_by) => {
+            let mut groups = BTreeMap::<_, Vec<_>>::new();
+            for item in &items {
+                for group in item.groups(group_by) {
+                    groups.entry(group).or_default().push(item);
+                }
+            }
+            write_groups(&mut out, format, &groups)?;
+        }
+    }
+    Ok(true)
+}
+
+fn write_items<W: Write>(out: &mut W, format: Format, items: &[Item]) -> io::Result<()> {
+    match format {
+        Format::Text => {
+            for item in items {
+                write_text(out, item)?;
+            }
+        }
+        Format::Markdown => write_markdown(out, items.iter())?,
+        Format::Json => {
+            serde_json::to_writer(&mut *out, items)?;
+            writeln!(out)?;
+        }
+    }
+    Ok(())
+}
+
+fn write_groups<W: Write>(
+    out: &mut W,
+    format: Format,
+    groups: &BTreeMap<String, Vec<&Item>>,
+) -> io::Result<()> {
+    match format {
+        Format::Text => {
+            for (group, items) in groups {
+                writeln!(out, "{} ({})", group, items.len())?;
+                for item in items {
+                    write!(out, "  ")?;
+                    write_text(out, item)?;
+                }
+            }
+        }
+        Format::Markdown => {
+            for (i, (group, items)) in groups.iter().enumerate() {
+                if i > 0 {
+                    writeln!(out)?;
+                }
+                writeln!(out, "## {}", group)?;
+                writeln!(out)?;
+                write_markdown(out, items.iter().cloned())?;
+            }
+        }
+        Format::Json => {
+            serde_json::to_writer(&mut *out, groups)?;
+            writeln!(out)?;
+        }
+    }
+    Ok(())
+}
+
+// Problems without a difficulty rating are shown with "-" rather than left out.
+fn difficulty(metadata: &Metadata) -> String {
+    metadata
+        .difficulty
+        .map(|d| format!("{}%", d))
+        .unwrap_or_else(|| "-".to_string())
+}
+
+fn write_text<W: Write>(out: &mut W, item: &Item) -> io::Result<()> {
+    let m = &item.metadata;
+    let mut line = format!(
+        "{} {:>4} {:<40}",
+        item.program,
+        difficulty(m),
+        m.title.unwrap_or("")
+    );
+    if !m.tags.is_empty() {
+        line.push_str(&format!(" [{}]", m.tags.join(", ")));
+    }
+    if !m.crates.is_empty() {
+        line.push_str(&format!(" ({})", m.crates.join(", ")));
+    }
+    writeln!(out, "{}", line.trim_end())
+}
+
+fn write_markdown<'a, W, I>(out: &mut W, items: I) -> io::Result<()>
+where
+    W: Write,
+    I: Iterator<Item = &'a Item>,
+{
+    writeln!(out, "| Problem | Title | Difficulty | Tags | Crates |")?;
+    writeln!(out, "|---------|-------|-----------:|------|--------|")?;
+    for item in items {
+        let m = &item.metadata;
+        writeln!(
+            out,
+            "| [{}]({}) | {} | {} | {} | {} |",
+            item.program,
+            item.url,
+            m.title.unwrap_or("").replace('|', "\\|"),
+            difficulty(m),
+            m.tags.join(", "),
+            m.crates.join(", ")
+        )?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{write_markdown, write_text, Filter, GroupBy, Item};
+    use common::metadata::Metadata;
+
+    fn item() -> Item {
+        Item {
+            program: "p010",
+            url: "https://projecteuler.net/problem=10".to_string(),
+            metadata: Metadata::default()
+                .title("Summation of primes")
+                .tags(&["primes", "sieve"])
+                .difficulty(5),
+        }
+    }
+
+    #[test]
+    fn filter() {
+        let item = item();
+        let filter = |tags: &[&str], crates: &[&str]| Filter {
+            tags: tags.iter().map(|s| s.to_string()).collect(),
+            crates: crates.iter().map(|s| s.to_string()).collect(),
+        };
+        assert!(filter(&[], &[]).matches(&item.metadata));
+        assert!(filter(&["primes", "sieve"], &[]).matches(&item.metadata));
+        assert!(!filter(&["primes", "dp"], &[]).matches(&item.metadata));
+        assert!(!filter(&[], &["prime"]).matches(&item.metadata));
+    }
+
+    #[test]
+    fn groups() {
+        let item = item();
+        assert_eq!(vec!["primes", "sieve"], item.groups(GroupBy::Tag));
+        assert_eq!(vec!["(none)"], item.groups(GroupBy::Crate));
+        assert_eq!(vec!["5%"], item.groups(GroupBy::Difficulty));
+    }
+
+    #[test]
+    fn no_difficulty() {
+        let item = Item {
+            metadata: Metadata::default().title("Amicable numbers"),
+            ..item()
+        };
+        assert_eq!(vec!["(none)"], item.groups(GroupBy::Difficulty));
+
+        let mut text = vec![];
+        write_text(&mut text, &item).unwrap();
+        assert_eq!(
+            "p010    - Amicable numbers\n",
+            String::from_utf8(text).unwrap()
+        );
+
+        let mut md = vec![];
+        write_markdown(&mut md, [item].iter()).unwrap();
+        assert!(String::from_utf8(md)
+            .unwrap()
+            .ends_with("| Amicable numbers | - |  |  |\n"));
+    }
+}
@@ -0,0 +1,1058 @@
+#![warn(
+    bad_style,
+    unused,
+    unused_extern_crates,
+    unused_import_braces,
+    unused_qualifications,
+    unused_results
+)]
+
+use crate::report::{Entry, Format};
+use common::{format_time, panic_message, profile, progress, SolverError, SolverResult};
+use failure::{bail, format_err};
+use getopts::Options;
+use glob::Paths;
+use std::{
+    collections::BTreeMap,
+    env,
+    fs::{self, File},
+    io::{self, prelude::*, BufReader},
+    mem,
+    os::unix::process::{CommandExt, ExitStatusExt},
+    panic::{self, AssertUnwindSafe},
+    path::{Path, PathBuf},
+    process,
+    process::{Child, Command, ExitStatus, Stdio},
+    str,
+    sync::{mpsc, Arc, Mutex},
+    thread,
+    time::{Duration, Instant},
+};
+
+mod hash;
+mod list;
+mod registry;
+mod report;
+mod scaffold;
+mod verify;
+
+const PROBLEM_EXE_PAT: &str = "p[0-9][0-9][0-9]";
+const LAST_RESULTS_PATH: &str = "./.cache/last_results.json";
+const POLL_INTERVAL: Duration = Duration::from_millis(5);
+
+type Result<T> = std::result::Result<T, failure::Error>;
+type Results = BTreeMap<String, SolverResult<String>>;
+
+fn problem_paths(dir_path: &Path) -> Result<Paths> {
+    let pat = dir_path.join(PROBLEM_EXE_PAT);
+    match pat.to_str() {
+        Some(x) => Ok(glob::glob(x)?),
+        None => bail!("path contains non-utf8 character"),
+    }
+}
+
+fn problem_number(program: &str) -> Option<u32> {
+    program.trim_start_matches('p').parse().ok()
+}
+
+// Parses selectors such as `12`, `50-75` and `1-10,12,20-25` into inclusive ranges.
+fn parse_selector(s: &str) -> Result<Vec<(u32, u32)>> {
+    let mut ranges = vec![];
+    for item in s.split(',').filter(|s| !s.is_empty()) {
+        let mut it = item.splitn(2, '-');
+        let start = it.next().unwrap().trim();
+        let end = it.next().map(str::trim).unwrap_or(start);
+        match (start.parse(), end.parse()) {
+            (Ok(start), Ok(end)) if start <= end => ranges.push((start, end)),
+            _ => bail!("invalid problem selector: {}", item),
+        }
+    }
+    Ok(ranges)
+}
+
+// Parses durations such as `1s`, `500ms` and `1.5s` into nanoseconds.
+fn parse_duration(s: &str) -> Result<u128> {
+    let idx = s
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(s.len());
+    let (num, unit) = s.split_at(idx);
+    let scale = match unit {
+        "ns" => 1.0,
+        "us" => 1e3,
+        "ms" => 1e6,
+        "s" | "" => 1e9,
+        "m" => 60e9,
+        _ => bail!("invalid duration: {}", s),
+    };
+    match num.parse::<f64>() {
+        Ok(n) => Ok((n * scale) as u128),
+        Err(_) => bail!("invalid duration: {}", s),
+    }
+}
+
+// Parses sizes such as `512M` and `2G` into bytes.
+fn parse_size(s: &str) -> Result<u64> {
+    let idx = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+    let (num, unit) = s.split_at(idx);
+    let scale = match unit {
+        "" => 1,
+        "K" => 1 << 10,
+        "M" => 1 << 20,
+        "G" => 1 << 30,
+        _ => bail!("invalid size: {}", s),
+    };
+    let n = match num.parse::<u64>() {
+        Ok(n) => n,
+        Err(_) => bail!("invalid size: {}", s),
+    };
+    match n.checked_mul(scale) {
+        Some(size) => Ok(size),
+        None => bail!("size too large: {}", s),
+    }
+}
+
+fn load_results(path: &Path) -> Result<Option<Results>> {
+    match File::open(path) {
+        Ok(file) => Ok(Some(serde_json::from_reader(file)?)),
+        Err(ref e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e.into()),
+    }
+}
+
+fn save_results(path: &Path, results: &Results) -> Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    serde_json::to_writer(File::create(path)?, results)?;
+    Ok(())
+}
+
+// Change of a problem's result from the baseline.
+#[derive(Clone, Debug, PartialEq)]
+struct Comparison {
+    program: String,
+    base_time: u128,
+    time: u128,
+    is_slower: bool,
+    is_answer_changed: bool,
+}
+
+impl Comparison {
+    fn is_regressed(&self) -> bool {
+        self.is_slower || self.is_answer_changed
+    }
+
+    // Percentage of the time change from the baseline.
+    fn change(&self) -> f64 {
+        if self.base_time == 0 {
+            return 0.0;
+        }
+        (self.time as f64 / self.base_time as f64 - 1.0) * 100.0
+    }
+}
+
+// Compares the problems in both `base` and `results`. A problem is slower if its time
+// exceeds the baseline by more than `threshold` percent.
+fn compare_results(base: &Results, results: &Results, threshold: f64) -> Vec<Comparison> {
+    results
+        .iter()
+        .filter_map(|(program, r)| {
+            let b = base.get(program)?;
+            Some(Comparison {
+                program: program.clone(),
+                base_time: b.time,
+                time: r.time,
+                is_slower: r.time as f64 > b.time as f64 * (1.0 + threshold / 100.0),
+                is_answer_changed: r.answer != b.answer,
+            })
+        })
+        .collect()
+}
+
+fn print_comparisons<W: Write>(out: &mut W, comps: &[Comparison], threshold: f64) {
+    let regressed = comps
+        .iter()
+        .filter(|c| c.is_regressed())
+        .collect::<Vec<_>>();
+    let _ = writeln!(
+        out,
+        "{} of {} problems regressed from the baseline (threshold: {}%)",
+        regressed.len(),
+        comps.len(),
+        threshold
+    );
+    if regressed.is_empty() {
+        return;
+    }
+    let _ = writeln!(
+        out,
+        "{:<8} {:>13} {:>13} {:>9}  STATUS",
+        "PROBLEM", "BASELINE", "CURRENT", "CHANGE"
+    );
+    for c in regressed {
+        let status = match (c.is_slower, c.is_answer_changed) {
+            (true, true) => "SLOWER, ANSWER CHANGED",
+            (true, false) => "SLOWER",
+            (false, _) => "ANSWER CHANGED",
+        };
+        let _ = writeln!(
+            out,
+            "{:<8} {:>13} {:>13} {:>+8.1}%  {}",
+            c.program,
+            format_time(c.base_time),
+            format_time(c.time),
+            c.change(),
+            status
+        );
+    }
+}
+
+// Prints the time spent in the spans of the solvers, summed by name over the
+// problems, with the number of problems recording each span.
+fn print_profile<W: Write>(
+    out: &mut W,
+    totals: &BTreeMap<String, (u128, usize)>,
+    total_time: u128,
+) {
+    let mut totals = totals.iter().collect::<Vec<_>>();
+    totals.sort_by(|a, b| (b.1).0.cmp(&(a.1).0).then(a.0.cmp(b.0)));
+    let width = totals
+        .iter()
+        .map(|(name, _)| name.len())
+        .max()
+        .unwrap_or(0)
+        .max(4);
+    let _ = writeln!(
+        out,
+        "{:<width$} {:>13} {:>6}  PROBLEMS",
+        "SPAN",
+        "TIME",
+        "SHARE",
+        width = width
+    );
+    for (name, &(time, problems)) in totals {
+        let share = if total_time > 0 {
+            time as f64 / total_time as f64 * 100.0
+        } else {
+            0.0
+        };
+        let _ = writeln!(
+            out,
+            "{:<width$} {:>13} {:>5.1}%  {}",
+            name,
+            format_time(time),
+            share,
+            problems,
+            width = width
+        );
+    }
+}
+
+#[derive(Copy, Clone, Debug, Default)]
+struct RunConfig {
+    timeout: Option<u128>,
+    memory: Option<u64>,
+    bench: Option<usize>,
+    warmup: Option<usize>,
+    in_process: bool,
+    stall: Option<u128>,
+}
+
+// Waits for the child, killing it if it runs longer than `timeout` nanoseconds.
+// `poll` is called with the elapsed nanoseconds while waiting.
+// Returns the exit status, the peak RSS in bytes and whether the child was killed.
+fn wait_child<F: FnMut(u128)>(
+    child: &mut Child,
+    timeout: Option<u128>,
+    mut poll: F,
+) -> io::Result<(ExitStatus, u64, bool)> {
+    let pid = child.id() as libc::pid_t;
+    let start_time = Instant::now();
+    let mut is_timeout = false;
+    loop {
+        poll(start_time.elapsed().as_nanos());
+        let timed_out = match timeout {
+            Some(t) => start_time.elapsed().as_nanos() > t,
+            None => false,
+        };
+        if timed_out && !is_timeout {
+            child.kill()?;
+            is_timeout = true;
+        }
+
+        let mut status = 0;
+        let mut usage = unsafe { mem::zeroed::<libc::rusage>() };
+        let options = if is_timeout { 0 } else { libc::WNOHANG };
+        match unsafe { libc::wait4(pid, &mut status, options, &mut usage) } {
+            0 => thread::sleep(POLL_INTERVAL),
+            -1 => {
+                let err = io::Error::last_os_error();
+                if err.kind() != io::ErrorKind::Interrupted {
+                    return Err(err);
+                }
+            }
+            _ => {
+                // `ru_maxrss` is in kilobytes on Linux.
+                let max_rss = usage.ru_maxrss as u64 * 1024;
+                return Ok((ExitStatus::from_raw(status), max_rss, is_timeout));
+            }
+        }
+    }
+}
+
+// Reports problems making no progress for a while.
+#[derive(Default)]
+struct Stall {
+    // Time of the progress event last reported as stalled, or `None` for the start.
+    reported: Option<Option<Instant>>,
+}
+
+impl Stall {
+    fn check(
+        &mut self,
+        program: &str,
+        limit: u128,
+        elapsed: u128,
+        last: &Option<(Instant, progress::Event)>,
+    ) {
+        let (since, key) = match *last {
+            Some((t, _)) => (t.elapsed().as_nanos(), Some(t)),
+            None => (elapsed, None),
+        };
+        if since <= limit || self.reported == Some(key) {
+            return;
+        }
+        self.reported = Some(key);
+        let last = match *last {
+            Some((_, ref event)) => format!("last progress: {}", event),
+            None => "no progress reported".to_string(),
+        };
+        let _ = writeln!(
+            &mut io::stderr(),
+            "{}: stalled for {} s ({})",
+            program,
+            format_time(since).trim_start(),
+            last
+        );
+    }
+}
+
+fn run_problem(path: &Path, config: RunConfig) -> Result<SolverResult<String>> {
+    let mut command = Command::new(path);
+    let _ = command
+        .arg("--json")
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    if let Some(runs) = config.bench {
+        let _ = command.arg("--bench").arg(runs.to_string());
+    }
+    if let Some(warmup) = config.warmup {
+        let _ = command.arg("--warmup").arg(warmup.to_string());
+    }
+    if let Some(memory) = config.memory {
+        let _ = unsafe {
+            command.pre_exec(move || {
+                let limit = libc::rlimit {
+                    rlim_cur: memory as libc::rlim_t,
+                    rlim_max: memory as libc::rlim_t,
+                };
+                if libc::setrlimit(libc::RLIMIT_AS, &limit) != 0 {
+                    return Err(io::Error::last_os_error());
+                }
+                Ok(())
+            })
+        };
+    }
+
+    let start_time = Instant::now();
+    let mut child = command.spawn()?;
+    let mut stdout = child.stdout.take().unwrap();
+    let stderr = child.stderr.take().unwrap();
+    let stdout = thread::spawn(move || {
+        let mut buf = vec![];
+        let _ = stdout.read_to_end(&mut buf);
+        buf
+    });
+    // Progress events are picked out of stderr as they arrive.
+    let last_progress = Arc::new(Mutex::new(None));
+    let stderr = {
+        let last_progress = Arc::clone(&last_progress);
+        thread::spawn(move || {
+            let mut buf = vec![];
+            for line in BufReader::new(stderr).split(b'\n') {
+                let line = match line {
+                    Ok(line) => line,
+                    Err(_) => break,
+                };
+                match str::from_utf8(&line).ok().and_then(progress::parse_event) {
+                    Some(event) => *last_progress.lock().unwrap() = Some((Instant::now(), event)),
+                    None => {
+                        buf.extend_from_slice(&line);
+                        buf.push(b'\n');
+                    }
+                }
+            }
+            buf
+        })
+    };
+    let program = path.file_name().unwrap().to_string_lossy();
+    let mut stall = Stall::default();
+    let (status, max_rss, is_timeout) = wait_child(&mut child, config.timeout, |elapsed| {
+        if let Some(limit) = config.stall {
+            stall.check(&program, limit, elapsed, &last_progress.lock().unwrap());
+        }
+    })?;
+    let elapsed = start_time.elapsed().as_nanos();
+    let stdout = stdout.join().unwrap();
+    let stderr = stderr.join().unwrap();
+
+    if !stderr.is_empty() {
+        let _ = match str::from_utf8(&stderr) {
+            Ok(s) => writeln!(&mut io::stderr(), "{}", s.trim()),
+            Err(e) => writeln!(&mut io::stderr(), "{:?}: {}", stderr, e),
+        };
+    }
+
+    if is_timeout {
+        return Ok(SolverResult {
+            time: elapsed,
+            answer: "".to_string(),
+            is_ok: false,
+            is_timeout: true,
+            max_rss: Some(max_rss),
+            ..Default::default()
+        });
+    }
+
+    // Problems failing with a `SolverError` report it in the JSON.
+    match status.code() {
+        Some(0) => {}
+        Some(st) if SolverError::is_exit_code(st) => {}
+        Some(st) => bail!("child process exit with {}", st),
+        None => bail!(
+            "child process exit with signal {}",
+            status.signal().unwrap()
+        ),
+    }
+
+    let mut result: SolverResult<String> = serde_json::from_reader(&mut &stdout[..])?;
+    result.max_rss = Some(max_rss);
+    Ok(result)
+}
+
+// Calls the solver linked into this executable. Limits are not applied.
+fn run_problem_in_process(program: &str, config: RunConfig) -> Result<SolverResult<String>> {
+    let solver = match registry::find(program) {
+        Some(solver) => solver,
+        None => bail!("unknown problem: {}", program),
+    };
+    let result = match config.bench {
+        Some(runs) => solver.bench(runs, config.warmup.unwrap_or(1)),
+        None => solver.solve(),
+    };
+    Ok(result.unwrap_or_else(SolverResult::from_error))
+}
+
+fn run_one(path: &Path, config: RunConfig) -> Result<SolverResult<String>> {
+    if config.in_process {
+        let program = path.file_name().unwrap().to_string_lossy();
+        run_problem_in_process(&program, config)
+    } else {
+        run_problem(path, config)
+    }
+}
+
+type ProblemOutput = (String, Result<SolverResult<String>>);
+type RunFn = fn(&Path, RunConfig) -> Result<SolverResult<String>>;
+
+fn run_problems<F>(paths: Vec<(PathBuf, RunConfig)>, jobs: usize, run: RunFn, mut f: F)
+where
+    F: FnMut(String, Result<SolverResult<String>>),
+{
+    let num_prob = paths.len();
+    let programs = paths
+        .iter()
+        .map(|(path, _)| path.file_name().unwrap().to_string_lossy().to_string())
+        .collect::<Vec<_>>();
+    let queue = Arc::new(Mutex::new(paths.into_iter().enumerate()));
+    let (tx, rx) = mpsc::channel();
+
+    let workers = (0..jobs)
+        .map(|_| {
+            let queue = Arc::clone(&queue);
+            let tx = tx.clone();
+            thread::spawn(move || loop {
+                let next = queue.lock().unwrap().next();
+                let (idx, (path, config)) = match next {
+                    Some(x) => x,
+                    None => break,
+                };
+                let program = path.file_name().unwrap().to_string_lossy().to_string();
+                // A panic fails only this problem, and the worker goes on to the next.
+                let result = panic::catch_unwind(AssertUnwindSafe(|| run(&path, config)))
+                    .unwrap_or_else(|payload| {
+                        Err(format_err!(
+                            "the runner panicked: {}",
+                            panic_message(&*payload)
+                        ))
+                    });
+                if tx.send((idx, (program, result))).is_err() {
+                    break;
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+    drop(tx);
+
+    // Outputs are reported in the problem order, as soon as all preceding problems are done.
+    let mut outputs: Vec<Option<ProblemOutput>> = (0..num_prob).map(|_| None).collect();
+    let mut next_idx = 0;
+    for (idx, output) in rx {
+        outputs[idx] = Some(output);
+        while next_idx < num_prob {
+            match outputs[next_idx].take() {
+                Some((program, result)) => f(program, result),
+                None => break,
+            }
+            next_idx += 1;
+        }
+    }
+
+    // The problems left without a result were lost in a worker that panicked
+    // outside of a run, or never run because all workers panicked.
+    let panics = workers
+        .into_iter()
+        .filter_map(|w| w.join().err())
+        .map(|payload| panic_message(&*payload))
+        .collect::<Vec<_>>();
+    for (idx, output) in outputs.into_iter().enumerate().skip(next_idx) {
+        match output {
+            Some((program, result)) => f(program, result),
+            None => f(
+                programs[idx].clone(),
+                Err(format_err!(
+                    "no result, the runner thread panicked: {}",
+                    panics.join("; ")
+                )),
+            ),
+        }
+    }
+}
+
+fn run() -> Result<bool> {
+    let args = env::args().collect::<Vec<_>>();
+    let program = &args[0];
+
+    if args.get(1).map(|s| &s[..]) == Some("verify-data") {
+        return verify::run(program, &args[2..]);
+    }
+    if args.get(1).map(|s| &s[..]) == Some("hash-answers") {
+        return hash::run(program, &args[2..]);
+    }
+    if args.get(1).map(|s| &s[..]) == Some("list") {
+        return list::run(program, &args[2..]);
+    }
+    if args.get(1).map(|s| &s[..]) == Some("new") {
+        return scaffold::run(program, &args[2..]);
+    }
+
+    let mut opts = Options::new();
+    let _ = opts.optopt("j", "jobs", "Run N problems in parallel (default: 1)", "N");
+    list::Filter::add_options(&mut opts);
+    let _ = opts.optflag(
+        "",
+        "only-failing",
+        "Run only the problems that failed in the last run",
+    );
+    let _ = opts.optopt(
+        "",
+        "slower-than",
+        "Run only the problems that took longer than TIME in the last run",
+        "TIME",
+    );
+    let _ = opts.optmulti(
+        "",
+        "timeout",
+        "Kill problems running longer than TIME, or problem N longer than TIME if N=TIME is given",
+        "[N=]TIME",
+    );
+    let _ = opts.optopt(
+        "",
+        "memory-limit",
+        "Limit the address space of each problem to SIZE bytes (K, M and G suffixes are allowed)",
+        "SIZE",
+    );
+    let _ = opts.optopt(
+        "",
+        "bench",
+        "Solve each problem N times and report statistics",
+        "N",
+    );
+    let _ = opts.optopt(
+        "",
+        "warmup",
+        "Solve each problem N times before benchmarking (default: 1)",
+        "N",
+    );
+    let _ = opts.optopt(
+        "",
+        "save-baseline",
+        "Save the results of this run to PATH as a baseline",
+        "PATH",
+    );
+    let _ = opts.optopt(
+        "",
+        "baseline",
+        "Compare the results with the baseline saved in PATH and fail on regressions",
+        "PATH",
+    );
+    let _ = opts.optopt(
+        "",
+        "regression-threshold",
+        "Flag problems more than PCT percent slower than the baseline (default: 10)",
+        "PCT",
+    );
+    let _ = opts.optopt(
+        "",
+        "format",
+        "Output format: text (default), junit, tap, markdown or json",
+        "FORMAT",
+    );
+    let _ = opts.optflag(
+        "",
+        "wall-time",
+        "Display the elapsed wall-clock time after the summed time",
+    );
+    let _ = opts.optflag(
+        "",
+        "profile",
+        "Display the time spent in the spans of the solvers, summed over the problems",
+    );
+    let _ = opts.optopt(
+        "",
+        "stall-after",
+        "Report problems making no progress for TIME",
+        "TIME",
+    );
+    let _ = opts.optflag(
+        "",
+        "in-process",
+        "Run the solvers linked into this executable instead of the problem executables",
+    );
+    let _ = opts.optflag("", "reveal", "Print the answers even if they are hashed");
+    common::data::add_options(&mut opts);
+    let _ = opts.optflag("h", "help", "Display this message");
+
+    let matches = opts.parse(&args[1..])?;
+    if matches.opt_present("h") {
+        let short = format!("{} [PROBLEMS...]", opts.short_usage(program));
+        println!("{}", opts.usage(&short));
+        println!(
+            "PROBLEMS are numbers, ranges or comma-separated lists, such as 12, 50-75 or 1-10,12"
+        );
+        println!();
+        println!("Subcommands:");
+        println!("    list           Print the catalogue of the problems");
+        println!("    new            Generate the source of a new problem");
+        println!("    verify-data    Verify the data files against the manifest");
+        println!("    hash-answers   Replace the plaintext answers in the sources with hashes");
+        return Ok(true);
+    }
+
+    // Problem processes inherit the data file locations from the environment.
+    common::data::apply_options(&matches)?;
+    if matches.opt_present("reveal") {
+        common::answer::reveal();
+    }
+
+    let mut ranges = vec![];
+    for s in &matches.free {
+        ranges.extend(parse_selector(s)?);
+    }
+    let slower_than = match matches.opt_str("slower-than") {
+        Some(s) => Some(parse_duration(&s)?),
+        None => None,
+    };
+    let only_failing = matches.opt_present("only-failing");
+    let filter = list::Filter::from_matches(&matches);
+
+    let mut timeout = None;
+    let mut problem_timeouts = BTreeMap::new();
+    for s in matches.opt_strs("timeout") {
+        match s.find('=') {
+            Some(idx) => match s[..idx].trim_start_matches('p').parse::<u32>() {
+                Ok(num) => {
+                    let _ = problem_timeouts.insert(num, parse_duration(&s[idx + 1..])?);
+                }
+                Err(_) => bail!("invalid problem number: {}", s),
+            },
+            None => timeout = Some(parse_duration(&s)?),
+        }
+    }
+    let memory = match matches.opt_str("memory-limit") {
+        Some(s) => Some(parse_size(&s)?),
+        None => None,
+    };
+    let stall = match matches.opt_str("stall-after") {
+        Some(s) => Some(parse_duration(&s)?),
+        None => None,
+    };
+    let in_process = matches.opt_present("in-process");
+    if in_process
+        && (timeout.is_some()
+            || !problem_timeouts.is_empty()
+            || memory.is_some()
+            || stall.is_some())
+    {
+        bail!("--timeout, --memory-limit and --stall-after cannot be used with --in-process");
+    }
+
+    let parse_count = |name: &str| -> Result<Option<usize>> {
+        match matches.opt_str(name) {
+            Some(s) => match s.parse::<usize>() {
+                Ok(n) => Ok(Some(n)),
+                Err(_) => bail!("invalid number of --{}: {}", name, s),
+            },
+            None => Ok(None),
+        }
+    };
+    let bench = match parse_count("bench")? {
+        Some(0) => bail!("invalid number of --bench: 0"),
+        n => n,
+    };
+    let warmup = parse_count("warmup")?;
+    if warmup.is_some() && bench.is_none() {
+        bail!("--warmup requires --bench");
+    }
+
+    let format = match matches.opt_str("format") {
+        Some(s) => match s.parse() {
+            Ok(f) => f,
+            Err(()) => bail!("invalid format: {}", s),
+        },
+        None => Format::Text,
+    };
+    let is_text = format == Format::Text;
+
+    let threshold = match matches.opt_str("regression-threshold") {
+        Some(s) => match s.parse::<f64>() {
+            Ok(n) if n >= 0.0 => n,
+            _ => bail!("invalid regression threshold: {}", s),
+        },
+        None => 10.0,
+    };
+    let baseline = match matches.opt_str("baseline") {
+        Some(s) => match load_results(Path::new(&s))? {
+            Some(rs) => Some(rs),
+            None => bail!("no baseline: {}", s),
+        },
+        None => None,
+    };
+
+    let mut last_results = load_results(Path::new(LAST_RESULTS_PATH))?;
+    if (only_failing || slower_than.is_some()) && last_results.is_none() {
+        bail!("no results of the last run: {}", LAST_RESULTS_PATH);
+    }
+
+    let jobs = match matches.opt_str("j") {
+        Some(s) => match s.parse::<usize>() {
+            Ok(n) if n > 0 => n,
+            _ => bail!("invalid number of jobs: {}", s),
+        },
+        None => 1,
+    };
+
+    let dir_path = {
+        let mut path = env::current_exe()?;
+        let _ = path.pop();
+        path
+    };
+    let paths = if in_process {
+        registry::names()
+            .into_iter()
+            .map(|name| dir_path.join(name))
+            .collect()
+    } else {
+        problem_paths(&dir_path)?.collect::<std::result::Result<Vec<_>, _>>()?
+    };
+    let paths = paths
+        .into_iter()
+        .filter(|path| {
+            let program = path.file_name().unwrap().to_string_lossy();
+            let num = problem_number(&program).unwrap();
+            if !ranges.is_empty() && !ranges.iter().any(|&(s, e)| s <= num && num <= e) {
+                return false;
+            }
+            if !filter.is_empty() {
+                match registry::find(&program) {
+                    Some(solver) if filter.matches(solver.metadata()) => {}
+                    _ => return false,
+                }
+            }
+            let last = last_results.as_ref().and_then(|rs| rs.get(&program[..]));
+            match (last, slower_than) {
+                (None, _) if only_failing || slower_than.is_some() => return false,
+                (Some(r), _) if only_failing && r.is_ok => return false,
+                (Some(r), Some(limit)) if r.time <= limit => return false,
+                _ => {}
+            }
+            true
+        })
+        .map(|path| {
+            let program = path.file_name().unwrap().to_string_lossy();
+            let num = problem_number(&program).unwrap();
+            let config = RunConfig {
+                timeout: problem_timeouts.get(&num).cloned().or(timeout),
+                memory,
+                bench,
+                warmup,
+                in_process,
+                stall,
+            };
+            (path, config)
+        })
+        .collect::<Vec<_>>();
+    let mut out = io::stdout();
+
+    let start_time = Instant::now();
+    let mut is_ok = true;
+    let mut num_prob = 0;
+    let mut total_time = 0;
+    let mut span_totals = BTreeMap::new();
+    let mut results = Results::new();
+    let mut entries = vec![];
+    run_problems(paths, jobs, run_one, |program, result| {
+        let r = match result {
+            Ok(r) => {
+                num_prob += 1;
+                total_time += r.time;
+                is_ok &= r.is_ok;
+                let mut totals = BTreeMap::new();
+                profile::totals(&r.spans, &mut totals);
+                for (name, time) in totals {
+                    let total = span_totals.entry(name).or_insert((0, 0));
+                    total.0 += time;
+                    total.1 += 1;
+                }
+                if is_text {
+                    let _ = r.print_pretty(&program, true);
+                }
+                entries.push(Entry {
+                    program: program.clone(),
+                    result: Ok(r.clone()),
+                });
+                r
+            }
+            Err(e) => {
+                is_ok = false;
+                if is_text {
+                    let _ = writeln!(&mut out, "{}: {:?}", program, e);
+                }
+                entries.push(Entry {
+                    program: program.clone(),
+                    result: Err(e.to_string()),
+                });
+                SolverResult {
+                    answer: "".to_string(),
+                    ..Default::default()
+                }
+            }
+        };
+        let _ = results.insert(program, r);
+    });
+    let wall_time = start_time.elapsed().as_nanos();
+
+    if let Some(path) = matches.opt_str("save-baseline") {
+        save_results(Path::new(&path), &results)?;
+    }
+    let comps = match baseline {
+        Some(ref base) => compare_results(base, &results, threshold),
+        None => vec![],
+    };
+
+    // Results of problems not run this time are kept from the last run.
+    let mut saved = last_results.take().unwrap_or_default();
+    saved.extend(results);
+    save_results(Path::new(LAST_RESULTS_PATH), &saved)?;
+
+    report::write(&mut out, format, &entries)?;
+
+    if is_text && num_prob > 0 {
+        let r = SolverResult {
+            time: total_time / num_prob,
+            answer: "".to_string(),
+            is_ok,
+            ..Default::default()
+        };
+        let _ = r.print_pretty(" AVG", true);
+
+        let r = SolverResult {
+            time: total_time,
+            answer: "".to_string(),
+            is_ok,
+            ..Default::default()
+        };
+        let _ = r.print_pretty(" SUM", false);
+
+        if matches.opt_present("wall-time") {
+            let r = SolverResult {
+                time: wall_time,
+                answer: "".to_string(),
+                is_ok,
+                ..Default::default()
+            };
+            let _ = r.print_pretty("WALL", false);
+        }
+    }
+
+    if matches.opt_present("profile") {
+        // Machine-readable reports own stdout.
+        if is_text {
+            print_profile(&mut out, &span_totals, total_time);
+        } else {
+            print_profile(&mut io::stderr(), &span_totals, total_time);
+        }
+    }
+
+    if baseline.is_some() {
+        // Machine-readable reports own stdout.
+        if is_text {
+            print_comparisons(&mut out, &comps, threshold);
+        } else {
+            print_comparisons(&mut io::stderr(), &comps, threshold);
+        }
+        if comps.iter().any(Comparison::is_regressed) {
+            return Ok(false);
+        }
+    }
+
+    Ok(is_ok)
+}
+
+fn main() {
+    match run() {
+        Ok(true) => process::exit(0),
+        Ok(false) => process::exit(1),
+        Err(e) => {
+            let _ = writeln!(&mut io::stderr(), "{:?}", e);
+            process::exit(255);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        compare_results, parse_duration, parse_selector, parse_size, run_problems, Result, Results,
+        RunConfig,
+    };
+    use common::SolverResult;
+    use std::path::{Path, PathBuf};
+
+    #[test]
+    fn selector() {
+        assert_eq!(vec![(12, 12)], parse_selector("12").unwrap());
+        assert_eq!(vec![(50, 75)], parse_selector("50-75").unwrap());
+        assert_eq!(
+            vec![(1, 10), (12, 12), (20, 25)],
+            parse_selector("1-10,12,20-25").unwrap()
+        );
+        assert!(parse_selector("75-50").is_err());
+        assert!(parse_selector("a").is_err());
+        assert!(parse_selector("1-").is_err());
+    }
+
+    #[test]
+    fn size() {
+        assert_eq!(100, parse_size("100").unwrap());
+        assert_eq!(512 << 20, parse_size("512M").unwrap());
+        assert_eq!(2 << 30, parse_size("2G").unwrap());
+        assert!(parse_size("1.5G").is_err());
+        assert!(parse_size("G").is_err());
+        assert_eq!(
+            "size too large: 99999999999999G",
+            parse_size("99999999999999G").unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn duration() {
+        assert_eq!(1_000_000_000, parse_duration("1s").unwrap());
+        assert_eq!(1_500_000_000, parse_duration("1.5s").unwrap());
+        assert_eq!(500_000_000, parse_duration("500ms").unwrap());
+        assert_eq!(20_000, parse_duration("20us").unwrap());
+        assert_eq!(2, parse_duration("2").unwrap() / 1_000_000_000);
+        assert!(parse_duration("1h").is_err());
+        assert!(parse_duration("s").is_err());
+    }
+
+    #[test]
+    fn baseline() {
+        fn results(rs: &[(&str, u128, &str)]) -> Results {
+            rs.iter()
+                .map(|&(program, time, answer)| {
+                    let r = SolverResult {
+                        time,
+                        answer: answer.to_string(),
+                        is_ok: true,
+                        ..Default::default()
+                    };
+                    (program.to_string(), r)
+                })
+                .collect()
+        }
+
+        let base = results(&[("p001", 100, "1"), ("p002", 100, "2"), ("p003", 100, "3")]);
+        let now = results(&[
+            ("p001", 110, "1"),
+            ("p002", 111, "2"),
+            ("p003", 50, "4"),
+            ("p004", 1, "4"),
+        ]);
+        let comps = compare_results(&base, &now, 10.0);
+        assert_eq!(
+            vec![
+                ("p001", false, false),
+                ("p002", true, false),
+                ("p003", false, true)
+            ],
+            comps
+                .iter()
+                .map(|c| (&c.program[..], c.is_slower, c.is_answer_changed))
+                .collect::<Vec<_>>()
+        );
+        assert!((comps[1].change() - 11.0).abs() < 1e-9);
+        assert!((comps[2].change() + 50.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn worker_panic() {
+        fn run(path: &Path, _: RunConfig) -> Result<SolverResult<String>> {
+            if path == Path::new("p002") {
+                panic!("runner bug");
+            }
+            Ok(SolverResult {
+                answer: path.display().to_string(),
+                ..Default::default()
+            })
+        }
+
+        for &jobs in &[1, 2] {
+            let paths = ["p001", "p002", "p003"]
+                .iter()
+                .map(|p| (PathBuf::from(p), RunConfig::default()))
+                .collect();
+            let mut outputs = vec![];
+            run_problems(paths, jobs, run, |program, result| {
+                outputs.push((program, result.map_err(|e| e.to_string())));
+            });
+            let programs = outputs.iter().map(|o| &o.0[..]).collect::<Vec<_>>();
+            assert_eq!(vec!["p001", "p002", "p003"], programs);
+            assert_eq!("p001", outputs[0].1.as_ref().unwrap().answer);
+            let e = outputs[1].1.as_ref().unwrap_err();
+            assert!(e.contains("runner bug"), "{}", e);
+            assert_eq!("p003", outputs[2].1.as_ref().unwrap().answer);
+        }
+    }
+}
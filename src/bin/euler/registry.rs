@@ -0,0 +1,28 @@
+//! Problem solvers linked into the runner.
+//!
+//! `build.rs` includes every `src/bin/pNNN.rs` as a module of this one. They are
+//! left out of the unit tests of the runner, as their binaries run their tests.
+
+use common::Solver;
+
+/// Name of a problem and the function returning its solver.
+pub type Entry = (&'static str, fn() -> Solver<'static>);
+
+#[cfg(not(test))]
+include!(concat!(env!("OUT_DIR"), "/registry.rs"));
+
+#[cfg(test)]
+pub const PROBLEMS: &[Entry] = &[];
+
+/// Returns the names of the registered problems, in ascending order.
+pub fn names() -> Vec<String> {
+    PROBLEMS.iter().map(|&(name, _)| name.to_string()).collect()
+}
+
+/// Returns the solver of the problem.
+pub fn find(program: &str) -> Option<Solver<'static>> {
+    PROBLEMS
+        .iter()
+        .find(|&&(name, _)| name == program)
+        .map(|&(_, solver)| solver())
+}
@@ -0,0 +1,431 @@
+//! Machine-readable reports of the results of a run.
+
+use common::{format_time, SolverError, SolverResult};
+use serde::Serialize;
+use std::{io, io::prelude::*, str::FromStr};
+
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+pub enum Format {
+    Text,
+    Junit,
+    Tap,
+    Markdown,
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Format, ()> {
+        match s {
+            "text" => Ok(Format::Text),
+            "junit" => Ok(Format::Junit),
+            "tap" => Ok(Format::Tap),
+            "markdown" | "md" => Ok(Format::Markdown),
+            "json" => Ok(Format::Json),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+enum Status {
+    Ok,
+    WrongAnswer,
+    Timeout,
+    Error,
+}
+
+impl Status {
+    fn as_str(self) -> &'static str {
+        match self {
+            Status::Ok => "OK",
+            Status::WrongAnswer => "NG",
+            Status::Timeout => "TIMEOUT",
+            Status::Error => "ERROR",
+        }
+    }
+}
+
+/// Result of a problem, or the error message if the problem could not be run.
+#[derive(Debug)]
+pub struct Entry {
+    pub program: String,
+    pub result: Result<SolverResult<String>, String>,
+}
+
+impl Entry {
+    fn status(&self) -> Status {
+        match self.result {
+            Ok(ref r) if r.is_timeout => Status::Timeout,
+            Ok(ref r) if r.is_ok => Status::Ok,
+            Ok(ref r) if self.solver_error(r).is_some() => Status::Error,
+            Ok(_) => Status::WrongAnswer,
+            Err(_) => Status::Error,
+        }
+    }
+
+    // Returns the error of the solver other than a wrong answer.
+    fn solver_error<'a>(&self, r: &'a SolverResult<String>) -> Option<&'a SolverError> {
+        match r.error {
+            Some(SolverError::WrongAnswer { .. }) | None => None,
+            Some(ref e) => Some(e),
+        }
+    }
+
+    // Describes the class of the error, such as `panic`, or `error` for errors of the runner.
+    fn error_kind(&self) -> &'static str {
+        match self.result {
+            Ok(ref r) => self.solver_error(r).map_or("error", SolverError::kind),
+            Err(_) => "error",
+        }
+    }
+
+    fn time(&self) -> u128 {
+        match self.result {
+            Ok(ref r) => r.time,
+            Err(_) => 0,
+        }
+    }
+
+    // Describes why the problem failed, or returns `None` if it passed.
+    fn failure(&self) -> Option<String> {
+        match self.result {
+            Ok(ref r) => match self.status() {
+                Status::Ok => None,
+                Status::Timeout => Some("timed out".to_string()),
+                Status::Error => self.solver_error(r).map(|e| e.to_string()),
+                _ => Some(format!(
+                    "expected: {}\nactual: {}",
+                    r.expected.as_ref().map(|s| &s[..]).unwrap_or("(unknown)"),
+                    r.answer
+                )),
+            },
+            Err(ref e) => Some(e.clone()),
+        }
+    }
+}
+
+/// Writes the report of the entries in the format.
+///
+/// Nothing is written in `Format::Text`, whose results are printed as they finish.
+pub fn write<W: Write>(out: &mut W, format: Format, entries: &[Entry]) -> io::Result<()> {
+    match format {
+        Format::Text => Ok(()),
+        Format::Junit => write_junit(out, entries),
+        Format::Tap => write_tap(out, entries),
+        Format::Markdown => write_markdown(out, entries),
+        Format::Json => write_json(out, entries),
+    }
+}
+
+fn count(entries: &[Entry], status: Status) -> usize {
+    entries.iter().filter(|e| e.status() == status).count()
+}
+
+fn seconds(nsec: u128) -> String {
+    format_time(nsec).trim_start().to_string()
+}
+
+fn escape_xml(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn write_junit<W: Write>(out: &mut W, entries: &[Entry]) -> io::Result<()> {
+    let failures = count(entries, Status::WrongAnswer) + count(entries, Status::Timeout);
+    let errors = count(entries, Status::Error);
+    let time = entries.iter().map(Entry::time).sum();
+
+    writeln!(out, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+    writeln!(
+        out,
+        r#"<testsuite name="euler" tests="{}" failures="{}" errors="{}" time="{}">"#,
+        entries.len(),
+        failures,
+        errors,
+        seconds(time)
+    )?;
+    for e in entries {
+        write!(
+            out,
+            r#"  <testcase classname="euler" name="{}" time="{}""#,
+            escape_xml(&e.program),
+            seconds(e.time())
+        )?;
+        let (tag, kind) = match e.status() {
+            Status::Ok => {
+                writeln!(out, "/>")?;
+                continue;
+            }
+            Status::WrongAnswer => ("failure", "wrong answer"),
+            Status::Timeout => ("failure", "timeout"),
+            Status::Error => ("error", e.error_kind()),
+        };
+        writeln!(out, ">")?;
+        writeln!(
+            out,
+            r#"    <{} message="{}">{}</{}>"#,
+            tag,
+            kind,
+            escape_xml(&e.failure().unwrap()),
+            tag
+        )?;
+        writeln!(out, "  </testcase>")?;
+    }
+    writeln!(out, "</testsuite>")?;
+    Ok(())
+}
+
+fn write_tap<W: Write>(out: &mut W, entries: &[Entry]) -> io::Result<()> {
+    writeln!(out, "TAP version 13")?;
+    writeln!(out, "1..{}", entries.len())?;
+    for (i, e) in entries.iter().enumerate() {
+        let failure = match e.failure() {
+            None => {
+                writeln!(
+                    out,
+                    "ok {} - {} # time={}",
+                    i + 1,
+                    e.program,
+                    seconds(e.time())
+                )?;
+                continue;
+            }
+            Some(f) => f,
+        };
+        writeln!(out, "not ok {} - {}", i + 1, e.program)?;
+        writeln!(out, "  ---")?;
+        writeln!(out, "  status: {}", e.status().as_str())?;
+        match e.result {
+            Ok(ref r) if e.status() == Status::WrongAnswer => {
+                if let Some(ref expected) = r.expected {
+                    writeln!(out, "  expected: {}", quote_yaml(expected))?;
+                }
+                writeln!(out, "  actual: {}", quote_yaml(&r.answer))?;
+            }
+            _ => writeln!(out, "  message: {}", quote_yaml(&failure))?,
+        }
+        writeln!(out, "  ...")?;
+    }
+    Ok(())
+}
+
+fn quote_yaml(s: &str) -> String {
+    format!("'{}'", s.replace('\'', "''").replace('\n', " "))
+}
+
+fn write_markdown<W: Write>(out: &mut W, entries: &[Entry]) -> io::Result<()> {
+    writeln!(out, "| Problem | Status | Time (s) | Answer |")?;
+    writeln!(out, "|---------|--------|---------:|--------|")?;
+    for e in entries {
+        let answer = match e.failure() {
+            Some(ref f) if e.status() == Status::Error => {
+                f.lines().next().unwrap_or("").to_string()
+            }
+            _ => match e.result {
+                Ok(ref r) => r.answer.clone(),
+                Err(_) => String::new(),
+            },
+        };
+        writeln!(
+            out,
+            "| {} | {} | {} | {} |",
+            e.program,
+            e.status().as_str(),
+            seconds(e.time()),
+            answer.replace('|', "\\|")
+        )?;
+    }
+    Ok(())
+}
+
+#[derive(Serialize)]
+struct JsonEntry<'a> {
+    program: &'a str,
+    status: &'static str,
+    #[serde(flatten)]
+    result: Option<&'a SolverResult<String>>,
+    // Errors of the solvers are in the `error` object of `result`. Errors of the
+    // runner, such as a crashed child process, are plain messages.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    runner_error: Option<&'a str>,
+}
+
+#[derive(Serialize)]
+struct JsonSummary {
+    total: usize,
+    passed: usize,
+    failed: usize,
+    errors: usize,
+    time: u128,
+}
+
+#[derive(Serialize)]
+struct JsonReport<'a> {
+    problems: Vec<JsonEntry<'a>>,
+    summary: JsonSummary,
+}
+
+fn write_json<W: Write>(out: &mut W, entries: &[Entry]) -> io::Result<()> {
+    let passed = count(entries, Status::Ok);
+    let errors = count(entries, Status::Error);
+    let report = JsonReport {
+        problems: entries
+            .iter()
+            .map(|e| JsonEntry {
+                program: &e.program,
+                status: e.status().as_str(),
+                result: e.result.as_ref().ok(),
+                runner_error: e.result.as_ref().err().map(|s| &s[..]),
+            })
+            .collect(),
+        summary: JsonSummary {
+            total: entries.len(),
+            passed,
+            failed: entries.len() - passed - errors,
+            errors,
+            time: entries.iter().map(Entry::time).sum(),
+        },
+    };
+    serde_json::to_writer(&mut *out, &report)?;
+    writeln!(out)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{write, Entry, Format};
+    use common::{SolverError, SolverResult};
+
+    fn entries() -> Vec<Entry> {
+        let ok = SolverResult {
+            time: 1_500_000_000,
+            answer: "233168".to_string(),
+            expected: Some("233168".to_string()),
+            is_ok: true,
+            ..Default::default()
+        };
+        let ng = SolverResult {
+            time: 2_000,
+            answer: "a|b<c>".to_string(),
+            expected: Some("4613732".to_string()),
+            is_ok: false,
+            ..Default::default()
+        };
+        vec![
+            Entry {
+                program: "p001".to_string(),
+                result: Ok(ok),
+            },
+            Entry {
+                program: "p002".to_string(),
+                result: Ok(ng),
+            },
+            Entry {
+                program: "p003".to_string(),
+                result: Err("child process exit with 101".to_string()),
+            },
+            Entry {
+                program: "p004".to_string(),
+                result: Ok(SolverResult::from_error(SolverError::Panic {
+                    message: "overflow".to_string(),
+                    location: Some("src/bin/p004.rs:10:5".to_string()),
+                    backtrace: None,
+                })),
+            },
+        ]
+    }
+
+    fn render(format: Format) -> String {
+        let mut out = vec![];
+        write(&mut out, format, &entries()).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn junit() {
+        let s = render(Format::Junit);
+        assert!(s.contains(
+            r#"<testsuite name="euler" tests="4" failures="1" errors="2" time="1.500002000">"#
+        ));
+        assert!(s.contains(r#"<testcase classname="euler" name="p001" time="1.500000000"/>"#));
+        assert!(s.contains(
+            r#"<failure message="wrong answer">expected: 4613732
+actual: a|b&lt;c&gt;</failure>"#
+        ));
+        assert!(s.contains(r#"<error message="error">child process exit with 101</error>"#));
+        assert!(s.contains(r#"<error message="panic">solver panicked: overflow</error>"#));
+    }
+
+    #[test]
+    fn tap() {
+        let expected = "TAP version 13
+1..4
+ok 1 - p001 # time=1.500000000
+not ok 2 - p002
+  ---
+  status: NG
+  expected: '4613732'
+  actual: 'a|b<c>'
+  ...
+not ok 3 - p003
+  ---
+  status: ERROR
+  message: 'child process exit with 101'
+  ...
+not ok 4 - p004
+  ---
+  status: ERROR
+  message: 'solver panicked: overflow'
+  ...
+";
+        assert_eq!(expected, render(Format::Tap));
+    }
+
+    #[test]
+    fn markdown() {
+        let expected = "| Problem | Status | Time (s) | Answer |
+|---------|--------|---------:|--------|
+| p001 | OK | 1.500000000 | 233168 |
+| p002 | NG | 0.000002000 | a\\|b<c> |
+| p003 | ERROR | 0.000000000 | child process exit with 101 |
+| p004 | ERROR | 0.000000000 | solver panicked: overflow |
+";
+        assert_eq!(expected, render(Format::Markdown));
+    }
+
+    #[test]
+    fn json() {
+        let v: serde_json::Value = serde_json::from_str(&render(Format::Json)).unwrap();
+        assert_eq!(4, v["problems"].as_array().unwrap().len());
+        assert_eq!("OK", v["problems"][0]["status"]);
+        assert_eq!("233168", v["problems"][0]["answer"]);
+        assert_eq!("4613732", v["problems"][1]["expected"]);
+        assert_eq!(
+            "child process exit with 101",
+            v["problems"][2]["runner_error"]
+        );
+        assert!(v["problems"][2].get("error").is_none());
+        assert_eq!("panic", v["problems"][3]["error"]["kind"]);
+        assert_eq!("overflow", v["problems"][3]["error"]["message"]);
+        assert_eq!(1, v["summary"]["passed"]);
+        assert_eq!(1, v["summary"]["failed"]);
+        assert_eq!(2, v["summary"]["errors"]);
+    }
+
+    #[test]
+    fn text() {
+        assert_eq!("", render(Format::Text));
+    }
+}
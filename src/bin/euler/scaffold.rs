@@ -0,0 +1,224 @@
+//! `euler new` subcommand.
+
+use crate::Result;
+use failure::bail;
+use getopts::Options;
+use std::{
+    fs::OpenOptions,
+    io::{self, prelude::*},
+    path::Path,
+};
+
+const PROBLEM_DIR: &str = "src/bin";
+
+const LINT_HEADER: &str = "#![warn(
+    bad_style,
+    unused,
+    unused_extern_crates,
+    unused_import_braces,
+    unused_qualifications,
+    unused_results
+)]
+";
+
+/// Generates the source of a new problem binary.
+pub fn run(program: &str, args: &[String]) -> Result<bool> {
+    let mut opts = Options::new();
+    let _ = opts.optopt(
+        "",
+        "file",
+        "Generate a solver reading the data file NAME",
+        "NAME",
+    );
+    let _ = opts.optopt("", "title", "Set the title in the metadata", "TITLE");
+    let _ = opts.optmulti(
+        "",
+        "tag",
+        "Add TAG to the metadata. May be given several times",
+        "TAG",
+    );
+    let _ = opts.optopt(
+        "",
+        "dir",
+        &format!("Generate the source in DIR (default: {})", PROBLEM_DIR),
+        "DIR",
+    );
+    let _ = opts.optflag("h", "help", "Display this message");
+
+    let matches = opts.parse(args)?;
+    if matches.opt_present("h") {
+        let short = format!("{} new [options] NUMBER", program);
+        println!("{}", opts.usage(&short));
+        return Ok(true);
+    }
+    let number = match &matches.free[..] {
+        [n] => match n.trim_start_matches('p').parse::<u32>() {
+            Ok(n) if n > 0 && n < 1000 => n,
+            _ => bail!("invalid problem number: {}", n),
+        },
+        [] => bail!("no problem number given"),
+        [_, rest @ ..] => bail!("unexpected argument: {}", rest[0]),
+    };
+    let template = Template {
+        number,
+        file_name: matches.opt_str("file"),
+        title: matches.opt_str("title"),
+        tags: matches.opt_strs("tag"),
+    };
+
+    let dir = matches
+        .opt_str("dir")
+        .unwrap_or_else(|| PROBLEM_DIR.to_string());
+    let path = Path::new(&dir).join(format!("p{:03}.rs", number));
+    // `create_new` fails if the file exists, so that no solver is overwritten.
+    let mut file = match OpenOptions::new().write(true).create_new(true).open(&path) {
+        Ok(file) => file,
+        Err(ref e) if e.kind() == io::ErrorKind::AlreadyExists => {
+            bail!("{} already exists", path.display())
+        }
+        Err(e) => bail!("{}: {}", path.display(), e),
+    };
+    file.write_all(template.render().as_bytes())?;
+    println!("created {}", path.display());
+    Ok(true)
+}
+
+struct Template {
+    number: u32,
+    file_name: Option<String>,
+    title: Option<String>,
+    tags: Vec<String>,
+}
+
+impl Template {
+    fn render(&self) -> String {
+        let mut s = format!(
+            "//! [Problem {0}](https://projecteuler.net/problem={0}) solver.\n\n",
+            self.number
+        );
+        s.push_str(LINT_HEADER);
+        s.push('\n');
+
+        // The answer is left empty, so that `test_solve` fails until it is filled in.
+        // `compute` returns a placeholder instead of panicking, since the runner
+        // links and runs every problem.
+        let answer = "\"\"";
+        let invocation = match self.file_name {
+            Some(ref file_name) => {
+                s.push_str(
+                    "use std::{
+    fs::File,
+    io::{self, prelude::*, BufReader},
+};
+
+// Placeholder, to be replaced by the solution.
+fn compute(lines: &[String]) -> u64 {
+    lines.len() as u64
+}
+
+fn solve(file: File) -> io::Result<String> {
+    let lines = BufReader::new(file)
+        .lines()
+        .collect::<io::Result<Vec<_>>>()?;
+    Ok(compute(&lines).to_string())
+}
+
+",
+                );
+                format!("{}, {:?}, solve", answer, file_name)
+            }
+            None => {
+                s.push_str(
+                    "// Placeholder, to be replaced by the solution.
+fn compute(limit: u64) -> u64 {
+    limit
+}
+
+fn solve() -> String {
+    compute(0).to_string()
+}
+
+",
+                );
+                format!("{}, solve", answer)
+            }
+        };
+
+        if self.title.is_none() && self.tags.is_empty() {
+            s.push_str(&format!("common::problem!({});\n", invocation));
+        } else {
+            let mut keys = vec![];
+            if let Some(ref title) = self.title {
+                keys.push(format!("    title: {:?}", title));
+            }
+            if !self.tags.is_empty() {
+                let tags = self
+                    .tags
+                    .iter()
+                    .map(|t| format!("{:?}", t))
+                    .collect::<Vec<_>>();
+                keys.push(format!("    tags: &[{}]", tags.join(", ")));
+            }
+            s.push_str(&format!(
+                "common::problem!(\n{};\n    {}\n);\n",
+                keys.join(",\n"),
+                invocation
+            ));
+        }
+
+        let example = match self.file_name {
+            Some(_) => "0, super::compute(&[])",
+            None => "10, super::compute(10)",
+        };
+        s.push_str(&format!(
+            "
+#[cfg(test)]
+mod tests {{
+    #[test]
+    fn example() {{
+        assert_eq!({});
+    }}
+}}
+",
+            example
+        ));
+        s
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Template;
+
+    #[test]
+    fn render() {
+        let t = Template {
+            number: 138,
+            file_name: None,
+            title: None,
+            tags: vec![],
+        };
+        let s = t.render();
+        assert!(s.starts_with("//! [Problem 138](https://projecteuler.net/problem=138) solver."));
+        assert!(s.contains("unused_results\n)]\n"));
+        assert!(s.contains("\ncommon::problem!(\"\", solve);\n"));
+        assert!(s.contains("\n#[cfg(test)]\nmod tests {\n"));
+        assert!(!s.contains("unimplemented!"));
+
+        let t = Template {
+            number: 139,
+            file_name: Some("p139.txt".to_string()),
+            title: Some("Pythagorean tiles".to_string()),
+            tags: vec!["geometry".to_string()],
+        };
+        let s = t.render();
+        assert!(s.contains("fn solve(file: File) -> io::Result<String>"));
+        assert!(s.contains(
+            "common::problem!(
+    title: \"Pythagorean tiles\",
+    tags: &[\"geometry\"];
+    \"\", \"p139.txt\", solve
+);"
+        ));
+    }
+}
@@ -0,0 +1,98 @@
+//! `euler verify-data` subcommand.
+
+use crate::{registry, Result};
+use common::data::{self, DataConfig, ManifestEntry, Verification};
+use failure::bail;
+use getopts::Options;
+use std::{
+    collections::BTreeSet,
+    fs::{self, File},
+    io,
+    path::Path,
+};
+
+/// Verifies the data files of the registered problems and the files in the
+/// manifest. Returns `false` if any file does not match the manifest.
+pub fn run(program: &str, args: &[String]) -> Result<bool> {
+    let mut opts = Options::new();
+    let _ = opts.optopt(
+        "",
+        "write-manifest",
+        "Write the digests of the data files in the data directory to PATH as a manifest",
+        "PATH",
+    );
+    data::add_options(&mut opts);
+    let _ = opts.optflag("h", "help", "Display this message");
+
+    let matches = opts.parse(args)?;
+    if matches.opt_present("h") {
+        let short = format!("{} verify-data [options]", program);
+        println!("{}", opts.usage(&short));
+        return Ok(true);
+    }
+    if !matches.free.is_empty() {
+        bail!("unexpected argument: {}", matches.free[0]);
+    }
+    data::apply_options(&matches)?;
+    let config = DataConfig::from_env()?;
+
+    let manifest = data::manifest();
+    let file_names = registry::PROBLEMS
+        .iter()
+        .filter_map(|&(_, solver)| solver().file_name())
+        .map(str::to_string)
+        .chain(manifest.iter().map(|e| e.file_name.clone()))
+        .collect::<BTreeSet<_>>();
+
+    let mut is_ok = true;
+    let mut entries = vec![];
+    for name in &file_names {
+        let path = config.path(name);
+        let content = match fs::read(&path) {
+            Ok(content) => content,
+            Err(ref e) if e.kind() == io::ErrorKind::NotFound => {
+                println!("{:<24} MISSING", name);
+                continue;
+            }
+            Err(e) => return Err(e.into()),
+        };
+        let v = data::verify(&manifest, name, &content);
+        if let Verification::Mismatch { .. } = v {
+            is_ok = false;
+        }
+        println!("{:<24} {}", name, v);
+        entries.push(ManifestEntry::new(name, &content));
+    }
+
+    if let Some(path) = matches.opt_str("write-manifest") {
+        data::write_manifest(&mut File::create(Path::new(&path))?, &entries)?;
+    }
+
+    Ok(is_ok)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::registry;
+    use common::data;
+
+    // Data files whose digests are not known yet. They are read unverified.
+    const UNLISTED: &[&str] = &["p079_keylog.txt", "p105_sets.txt", "p107_network.txt"];
+
+    #[test]
+    fn manifest_lists_data_files() {
+        let manifest = data::manifest();
+        for &(program, solver) in registry::PROBLEMS {
+            let name = match solver().file_name() {
+                Some(name) => name,
+                None => continue,
+            };
+            let is_listed = manifest.iter().any(|e| e.file_name == name);
+            if UNLISTED.contains(&name) {
+                assert!(!is_listed, "{} is listed, remove it from UNLISTED", name);
+            } else {
+                assert!(is_listed, "{} of {} is not in the manifest", name, program);
+            }
+        }
+    }
+}
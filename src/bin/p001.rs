@@ -17,7 +17,13 @@ fn solve() -> String {
     compute(1000).to_string()
 }
 
-common::problem!("233168", solve);
+common::problem!(
+    title: "Multiples of 3 and 5",
+    tags: &["arithmetic"],
+    difficulty: 5,
+    crates: &[];
+    "233168", solve
+);
 
 #[cfg(test)]
 mod tests {
@@ -22,7 +22,13 @@ fn compute(bound: u32) -> u32 {
 fn solve() -> String {
     compute(4000000).to_string()
 }
-common::problem!("4613732", solve);
+common::problem!(
+    title: "Even Fibonacci numbers",
+    tags: &["fibonacci"],
+    difficulty: 5,
+    crates: &["num-integer", "seq"];
+    "4613732", solve
+);
 
 #[cfg(test)]
 mod tests {
@@ -19,7 +19,13 @@ fn compute(n: u64) -> u64 {
 fn solve() -> String {
     compute(600851475143).to_string()
 }
-common::problem!("6857", solve);
+common::problem!(
+    title: "Largest prime factor",
+    tags: &["primes", "factorization"],
+    difficulty: 5,
+    crates: &["prime"];
+    "6857", solve
+);
 
 #[cfg(test)]
 mod tests {
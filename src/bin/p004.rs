@@ -37,7 +37,13 @@ fn solve() -> String {
     compute(100, 999).to_string()
 }
 
-common::problem!("906609", solve);
+common::problem!(
+    title: "Largest palindrome product",
+    tags: &["palindromes", "digits"],
+    difficulty: 5,
+    crates: &["integer", "num-integer"];
+    "906609", solve
+);
 
 #[cfg(test)]
 mod tests {
@@ -24,7 +24,13 @@ fn solve() -> String {
     compute(20).to_string()
 }
 
-common::problem!("232792560", solve);
+common::problem!(
+    title: "Smallest multiple",
+    tags: &["primes", "factorization"],
+    difficulty: 5,
+    crates: &["prime"];
+    "232792560", solve
+);
 
 #[cfg(test)]
 mod tests {
@@ -28,7 +28,13 @@ fn solve() -> String {
     compute(100).to_string()
 }
 
-common::problem!("25164150", solve);
+common::problem!(
+    title: "Sum square difference",
+    tags: &["arithmetic"],
+    difficulty: 5,
+    crates: &[];
+    "25164150", solve
+);
 
 #[cfg(test)]
 mod tests {
@@ -19,7 +19,13 @@ fn solve() -> String {
     compute(10001 - 1).to_string()
 }
 
-common::problem!("104743", solve);
+common::problem!(
+    title: "10001st prime",
+    tags: &["primes"],
+    difficulty: 5,
+    crates: &["prime"];
+    "104743", solve
+);
 
 #[cfg(test)]
 mod tests {
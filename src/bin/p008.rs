@@ -48,7 +48,13 @@ fn solve() -> String {
     compute(13).to_string()
 }
 
-common::problem!("23514624000", solve);
+common::problem!(
+    title: "Largest product in a series",
+    tags: &["digits"],
+    difficulty: 5,
+    crates: &[];
+    "23514624000", solve
+);
 
 #[cfg(test)]
 mod tests {
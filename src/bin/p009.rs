@@ -28,4 +28,10 @@ fn solve() -> String {
     compute(1000).to_string()
 }
 
-common::problem!("31875000", solve);
+common::problem!(
+    title: "Special Pythagorean triplet",
+    tags: &["pythagorean-triples"],
+    difficulty: 5,
+    crates: &["integer"];
+    "31875000", solve
+);
@@ -16,16 +16,33 @@ fn compute(limit: u64) -> u64 {
     ps.iter().take_while(|&p| p < limit).sum()
 }
 
+fn compute_naive(limit: u64) -> u64 {
+    (2..limit)
+        .filter(|&n| (2..).take_while(|&d| d * d <= n).all(|d| n % d != 0))
+        .sum()
+}
+
 fn solve() -> String {
     compute(2000000).to_string()
 }
 
-common::problem!("142913828922", solve);
+fn solve_naive() -> String {
+    compute_naive(2000000).to_string()
+}
+
+common::problem!(
+    title: "Summation of primes",
+    tags: &["primes", "sieve"],
+    difficulty: 5,
+    crates: &["prime"];
+    "142913828922", sieve => solve, naive => solve_naive
+);
 
 #[cfg(test)]
 mod tests {
     #[test]
     fn four_seq() {
         assert_eq!(17, super::compute(10));
+        assert_eq!(17, super::compute_naive(10));
     }
 }
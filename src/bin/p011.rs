@@ -89,4 +89,9 @@ fn solve() -> String {
     compute(4).to_string()
 }
 
-common::problem!("70600674", solve);
+common::problem!(
+    title: "Largest product in a grid",
+    tags: &["grid"],
+    crates: &[];
+    "70600674", solve
+);
@@ -24,7 +24,12 @@ fn solve() -> String {
     compute(500).to_string()
 }
 
-common::problem!("76576500", solve);
+common::problem!(
+    title: "Highly divisible triangular number",
+    tags: &["divisors", "figurate-numbers"],
+    crates: &["prime", "seq"];
+    "76576500", solve
+);
 
 #[cfg(test)]
 mod tests {
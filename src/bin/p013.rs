@@ -127,4 +127,9 @@ fn solve() -> String {
     compute()
 }
 
-common::problem!("5537376230", solve);
+common::problem!(
+    title: "Large sum",
+    tags: &["arithmetic", "digits"],
+    crates: &[];
+    "5537376230", solve
+);
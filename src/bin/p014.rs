@@ -9,6 +9,7 @@
     unused_results
 )]
 
+use common::Params;
 use seq::Collatz;
 use std::collections::HashMap;
 
@@ -33,8 +34,13 @@ fn compute(limit: u64) -> u64 {
         .unwrap()
 }
 
-fn solve() -> String {
-    compute(1000000).to_string()
+fn solve(params: &Params) -> String {
+    compute(params.get("limit")).to_string()
 }
 
-common::problem!("837799", solve);
+common::problem!(
+    title: "Longest Collatz sequence",
+    tags: &["collatz", "memoization"],
+    crates: &["seq"];
+    "837799", solve, limit: u64 = 1_000_000
+);
@@ -19,7 +19,12 @@ fn solve() -> String {
     compute(20, 20).to_string()
 }
 
-common::problem!("137846528820", solve);
+common::problem!(
+    title: "Lattice paths",
+    tags: &["combinatorics"],
+    crates: &["prime"];
+    "137846528820", solve
+);
 
 #[cfg(test)]
 mod tests {
@@ -25,7 +25,12 @@ fn solve() -> String {
     compute(2, 1000).to_string()
 }
 
-common::problem!("1366", solve);
+common::problem!(
+    title: "Power digit sum",
+    tags: &["bignum", "digits"],
+    crates: &["num-bigint", "num-traits"];
+    "1366", solve
+);
 
 #[cfg(test)]
 mod tests {
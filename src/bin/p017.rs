@@ -103,7 +103,12 @@ fn solve() -> String {
     compute(1000).to_string()
 }
 
-common::problem!("21124", solve);
+common::problem!(
+    title: "Number letter counts",
+    tags: &["words"],
+    crates: &[];
+    "21124", solve
+);
 
 #[cfg(test)]
 mod tests {
@@ -52,7 +52,12 @@ fn solve() -> String {
     compute(&parse(TRIANGLE).unwrap()).to_string()
 }
 
-common::problem!("1074", solve);
+common::problem!(
+    title: "Maximum path sum I",
+    tags: &["dp"],
+    crates: &[];
+    "1074", solve
+);
 
 #[cfg(test)]
 mod tests {
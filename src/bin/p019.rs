@@ -71,4 +71,9 @@ fn solve() -> String {
     compute().to_string()
 }
 
-common::problem!("171", solve);
+common::problem!(
+    title: "Counting Sundays",
+    tags: &["calendar"],
+    crates: &[];
+    "171", solve
+);
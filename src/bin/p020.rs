@@ -28,7 +28,12 @@ fn solve() -> String {
     compute(100).to_string()
 }
 
-common::problem!("648", solve);
+common::problem!(
+    title: "Factorial digit sum",
+    tags: &["bignum", "digits"],
+    crates: &["num-bigint", "num-iter", "num-traits"];
+    "648", solve
+);
 
 #[cfg(test)]
 mod tests {
@@ -33,4 +33,9 @@ fn solve() -> String {
     compute(10000).to_string()
 }
 
-common::problem!("31626", solve);
+common::problem!(
+    title: "Amicable numbers",
+    tags: &["divisors"],
+    crates: &["prime"];
+    "31626", solve
+);
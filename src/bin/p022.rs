@@ -19,6 +19,7 @@ fn get_score(n: u32, s: &str) -> u32 {
 }
 
 fn compute(words: &[String]) -> u32 {
+    let _t = common::span("score");
     let mut words = words
         .iter()
         .map(|word| word.trim().trim_matches('\"'))
@@ -35,6 +36,7 @@ fn compute(words: &[String]) -> u32 {
 fn solve(file: File) -> io::Result<String> {
     let mut words = vec![];
 
+    let _t = common::span("read");
     for bytes in BufReader::new(file).split(b',') {
         let mut bytes = bytes?;
         if bytes.last() == Some(&b',') {
@@ -42,11 +44,17 @@ fn solve(file: File) -> io::Result<String> {
         }
         words.push(String::from_utf8(bytes).unwrap());
     }
+    drop(_t);
 
     Ok(compute(&words).to_string())
 }
 
-common::problem!("871198282", "p022_names.txt", solve);
+common::problem!(
+    title: "Names scores",
+    tags: &["words", "sorting"],
+    crates: &[];
+    "871198282", "p022_names.txt", solve
+);
 
 #[cfg(test)]
 mod tests {
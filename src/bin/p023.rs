@@ -14,10 +14,14 @@ use prime::{Factorize, PrimeSet};
 fn compute(max: u64) -> u64 {
     let ps = PrimeSet::new();
 
-    let abundant = (2..max + 1)
-        .filter(|&n| n.sum_of_proper_divisor(&ps) > n)
-        .collect::<Vec<_>>();
-
+    let abundant = {
+        let _t = common::span("abundant");
+        (2..max + 1)
+            .filter(|&n| n.sum_of_proper_divisor(&ps) > n)
+            .collect::<Vec<_>>()
+    };
+
+    let _t = common::span("sums");
     let mut sum_of_sum_abundant = 0;
 
     let mut is_sum_abundant = vec![false; (max + 1) as usize];
@@ -43,4 +47,9 @@ fn solve() -> String {
     compute(28123).to_string()
 }
 
-common::problem!("4179871", solve);
+common::problem!(
+    title: "Non-abundant sums",
+    tags: &["divisors"],
+    crates: &["prime"];
+    "4179871", solve
+);
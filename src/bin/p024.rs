@@ -27,7 +27,12 @@ fn solve() -> String {
     compute(1000000 - 1, vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9]).to_string()
 }
 
-common::problem!("2783915460", solve);
+common::problem!(
+    title: "Lexicographic permutations",
+    tags: &["permutations"],
+    crates: &["integer", "num-integer"];
+    "2783915460", solve
+);
 
 #[cfg(test)]
 mod tests {
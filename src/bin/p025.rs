@@ -29,7 +29,12 @@ fn solve() -> String {
     compute(1000).to_string()
 }
 
-common::problem!("4782", solve);
+common::problem!(
+    title: "1000-digit Fibonacci number",
+    tags: &["fibonacci", "bignum"],
+    crates: &["num-bigint", "seq"];
+    "4782", solve
+);
 
 #[cfg(test)]
 mod tests {
@@ -39,7 +39,12 @@ fn solve() -> String {
     compute(1000).to_string()
 }
 
-common::problem!("983", solve);
+common::problem!(
+    title: "Reciprocal cycles",
+    tags: &["fractions", "modular-arithmetic"],
+    crates: &[];
+    "983", solve
+);
 
 #[cfg(test)]
 mod tests {
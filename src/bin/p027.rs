@@ -45,7 +45,12 @@ fn solve() -> String {
     compute(1000).to_string()
 }
 
-common::problem!("-59231", solve);
+common::problem!(
+    title: "Quadratic primes",
+    tags: &["primes"],
+    crates: &["prime"];
+    "-59231", solve
+);
 
 #[cfg(test)]
 mod tests {
@@ -43,7 +43,12 @@ fn solve() -> String {
     compute(1001).to_string()
 }
 
-common::problem!("669171001", solve);
+common::problem!(
+    title: "Number spiral diagonals",
+    tags: &["arithmetic"],
+    crates: &[];
+    "669171001", solve
+);
 
 #[cfg(test)]
 mod tests {
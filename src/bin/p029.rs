@@ -33,7 +33,12 @@ fn solve() -> String {
     compute(100, 100).to_string()
 }
 
-common::problem!("9183", solve);
+common::problem!(
+    title: "Distinct powers",
+    tags: &["factorization"],
+    crates: &["prime"];
+    "9183", solve
+);
 
 #[cfg(test)]
 mod tests {
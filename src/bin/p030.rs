@@ -40,7 +40,12 @@ fn solve() -> String {
     compute(6, 5).to_string()
 }
 
-common::problem!("443839", solve);
+common::problem!(
+    title: "Digit fifth powers",
+    tags: &["digits"],
+    crates: &["integer", "iter"];
+    "443839", solve
+);
 
 #[cfg(test)]
 mod tests {
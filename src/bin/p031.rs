@@ -31,7 +31,12 @@ fn solve() -> String {
     compute(200).to_string()
 }
 
-common::problem!("73682", solve);
+common::problem!(
+    title: "Coin sums",
+    tags: &["partitions", "dp"],
+    crates: &[];
+    "73682", solve
+);
 
 #[cfg(test)]
 mod tests {
@@ -72,4 +72,9 @@ fn solve() -> String {
     compute().to_string()
 }
 
-common::problem!("45228", solve);
+common::problem!(
+    title: "Pandigital products",
+    tags: &["pandigital"],
+    crates: &["integer", "iter"];
+    "45228", solve
+);
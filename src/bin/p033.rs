@@ -55,4 +55,9 @@ fn solve() -> String {
     compute().to_string()
 }
 
-common::problem!("100", solve);
+common::problem!(
+    title: "Digit cancelling fractions",
+    tags: &["fractions", "digits"],
+    crates: &["num-integer"];
+    "100", solve
+);
@@ -35,4 +35,9 @@ fn solve() -> String {
     compute().to_string()
 }
 
-common::problem!("40730", solve);
+common::problem!(
+    title: "Digit factorials",
+    tags: &["digits"],
+    crates: &[];
+    "40730", solve
+);
@@ -42,7 +42,12 @@ fn solve() -> String {
     compute(1000000).to_string()
 }
 
-common::problem!("55", solve);
+common::problem!(
+    title: "Circular primes",
+    tags: &["primes", "digits"],
+    crates: &["integer", "prime"];
+    "55", solve
+);
 
 #[cfg(test)]
 mod tests {
@@ -37,4 +37,9 @@ fn solve() -> String {
     compute(1000000).to_string()
 }
 
-common::problem!("872187", solve);
+common::problem!(
+    title: "Double-base palindromes",
+    tags: &["palindromes"],
+    crates: &["integer"];
+    "872187", solve
+);
@@ -64,7 +64,12 @@ fn solve() -> String {
     compute().to_string()
 }
 
-common::problem!("748317", solve);
+common::problem!(
+    title: "Truncatable primes",
+    tags: &["primes", "digits"],
+    crates: &["integer", "prime"];
+    "748317", solve
+);
 
 #[cfg(test)]
 mod tests {
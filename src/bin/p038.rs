@@ -35,4 +35,9 @@ fn solve() -> String {
     compute()
 }
 
-common::problem!("932718654", solve);
+common::problem!(
+    title: "Pandigital multiples",
+    tags: &["pandigital"],
+    crates: &["integer", "iter"];
+    "932718654", solve
+);
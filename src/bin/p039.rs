@@ -39,7 +39,12 @@ fn solve() -> String {
     compute(1000).to_string()
 }
 
-common::problem!("840", solve);
+common::problem!(
+    title: "Integer right triangles",
+    tags: &["pythagorean-triples"],
+    crates: &["integer", "seq"];
+    "840", solve
+);
 
 #[cfg(test)]
 mod tests {
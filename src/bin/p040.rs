@@ -69,7 +69,12 @@ fn solve() -> String {
     compute(idxs, 10).to_string()
 }
 
-common::problem!("210", solve);
+common::problem!(
+    title: "Champernowne's constant",
+    tags: &["digits"],
+    crates: &["integer", "num-integer"];
+    "210", solve
+);
 
 #[cfg(test)]
 mod tests {
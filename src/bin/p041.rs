@@ -33,4 +33,9 @@ fn solve() -> String {
     compute().to_string()
 }
 
-common::problem!("7652413", solve);
+common::problem!(
+    title: "Pandigital prime",
+    tags: &["pandigital", "primes"],
+    crates: &["integer", "iter", "prime"];
+    "7652413", solve
+);
@@ -44,7 +44,12 @@ fn solve(file: File) -> io::Result<String> {
         .to_string())
 }
 
-common::problem!("162", "p042_words.txt", solve);
+common::problem!(
+    title: "Coded triangle numbers",
+    tags: &["words", "figurate-numbers"],
+    crates: &["seq"];
+    "162", "p042_words.txt", solve
+);
 
 #[cfg(test)]
 mod tests {
@@ -108,7 +108,12 @@ fn solve() -> String {
     result.iter().map(|pd| pd.to_u64()).sum::<u64>().to_string()
 }
 
-common::problem!("16695334890", solve);
+common::problem!(
+    title: "Sub-string divisibility",
+    tags: &["pandigital"],
+    crates: &["integer"];
+    "16695334890", solve
+);
 
 #[cfg(test)]
 mod tests {
@@ -47,4 +47,9 @@ fn solve() -> String {
     unreachable!()
 }
 
-common::problem!("5482660", solve);
+common::problem!(
+    title: "Pentagon numbers",
+    tags: &["figurate-numbers"],
+    crates: &[];
+    "5482660", solve
+);
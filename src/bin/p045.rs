@@ -71,7 +71,12 @@ fn solve() -> String {
     compute(40755 + 1).to_string()
 }
 
-common::problem!("1533776805", solve);
+common::problem!(
+    title: "Triangular, pentagonal, and hexagonal",
+    tags: &["figurate-numbers"],
+    crates: &[];
+    "1533776805", solve
+);
 
 #[cfg(test)]
 mod tests {
@@ -35,4 +35,9 @@ fn solve() -> String {
         .to_string()
 }
 
-common::problem!("5777", solve);
+common::problem!(
+    title: "Goldbach's other conjecture",
+    tags: &["primes"],
+    crates: &["integer", "prime"];
+    "5777", solve
+);
@@ -34,7 +34,12 @@ fn solve() -> String {
     compute(4, 4).to_string()
 }
 
-common::problem!("134043", solve);
+common::problem!(
+    title: "Distinct primes factors",
+    tags: &["factorization"],
+    crates: &["prime"];
+    "134043", solve
+);
 
 #[cfg(test)]
 mod tests {
@@ -28,7 +28,12 @@ fn solve() -> String {
     compute(1000, 100_0000_0000).to_string()
 }
 
-common::problem!("9110846700", solve);
+common::problem!(
+    title: "Self powers",
+    tags: &["modular-arithmetic"],
+    crates: &["num-bigint", "num-traits"];
+    "9110846700", solve
+);
 
 #[cfg(test)]
 mod tests {
@@ -31,4 +31,9 @@ fn solve() -> String {
     format!("{}{}{}", p1, p2, p3)
 }
 
-common::problem!("296962999629", solve);
+common::problem!(
+    title: "Prime permutations",
+    tags: &["primes", "permutations"],
+    crates: &["integer", "prime"];
+    "296962999629", solve
+);
@@ -69,7 +69,12 @@ fn solve() -> String {
     compute(1000000).0.to_string()
 }
 
-common::problem!("997651", solve);
+common::problem!(
+    title: "Consecutive prime sum",
+    tags: &["primes"],
+    crates: &["prime"];
+    "997651", solve
+);
 
 #[cfg(test)]
 mod tests {
@@ -52,7 +52,12 @@ fn solve() -> String {
     compute(8).to_string()
 }
 
-common::problem!("121313", solve);
+common::problem!(
+    title: "Prime digit replacements",
+    tags: &["primes", "digits"],
+    crates: &["integer", "prime"];
+    "121313", solve
+);
 
 #[cfg(test)]
 mod tests {
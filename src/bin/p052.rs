@@ -63,4 +63,9 @@ fn solve() -> String {
     compute().to_string()
 }
 
-common::problem!("142857", solve);
+common::problem!(
+    title: "Permuted multiples",
+    tags: &["digits"],
+    crates: &["integer"];
+    "142857", solve
+);
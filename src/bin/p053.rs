@@ -49,4 +49,9 @@ fn solve() -> String {
     compute().to_string()
 }
 
-common::problem!("4075", solve);
+common::problem!(
+    title: "Combinatoric selections",
+    tags: &["combinatorics"],
+    crates: &[];
+    "4075", solve
+);
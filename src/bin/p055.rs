@@ -40,7 +40,12 @@ fn solve() -> String {
     compute(10000, 50).to_string()
 }
 
-common::problem!("249", solve);
+common::problem!(
+    title: "Lychrel numbers",
+    tags: &["palindromes", "bignum"],
+    crates: &["num-bigint", "num-traits"];
+    "249", solve
+);
 
 #[cfg(test)]
 mod tests {
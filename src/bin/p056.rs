@@ -32,4 +32,9 @@ fn solve() -> String {
     compute(100, 100).to_string()
 }
 
-common::problem!("972", solve);
+common::problem!(
+    title: "Powerful digit sum",
+    tags: &["bignum", "digits"],
+    crates: &["itertools", "num-bigint", "num-iter", "num-traits"];
+    "972", solve
+);
@@ -63,7 +63,12 @@ fn solve() -> String {
         .to_string()
 }
 
-common::problem!("153", solve);
+common::problem!(
+    title: "Square root convergents",
+    tags: &["continued-fractions", "bignum"],
+    crates: &["num-bigint", "num-traits"];
+    "153", solve
+);
 
 #[cfg(test)]
 mod tests {
@@ -43,4 +43,9 @@ fn solve() -> String {
     compute(1, 10).to_string()
 }
 
-common::problem!("26241", solve);
+common::problem!(
+    title: "Spiral primes",
+    tags: &["primes"],
+    crates: &["prime"];
+    "26241", solve
+);
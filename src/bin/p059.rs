@@ -123,4 +123,9 @@ fn solve(file: File) -> io::Result<String> {
     Ok(sum.to_string())
 }
 
-common::problem!("129448", "p059_cipher.txt", solve);
+common::problem!(
+    title: "XOR decryption",
+    tags: &["cryptography"],
+    crates: &[];
+    "129448", "p059_cipher.txt", solve
+);
@@ -9,6 +9,7 @@
     unused_results
 )]
 
+use common::progress;
 use prime::PrimeSet;
 use std::collections::HashMap;
 
@@ -39,6 +40,8 @@ impl Iterator for ConcatPrimeNums {
 
     fn next(&mut self) -> Option<(u64, Vec<u64>)> {
         let n = self.iter.next().unwrap();
+        // Includes growing the prime set, which the primality tests trigger.
+        let _t = common::span("pairs");
         let pairs = self
             .ps
             .iter()
@@ -101,6 +104,7 @@ fn compute(len: usize) -> Vec<u64> {
 
     for (n, pairs) in ConcatPrimeNums::new(&prime) {
         if pairs.len() >= len {
+            let _t = common::span("chains");
             for set in find_chain(&pairs, &[n], &map) {
                 if set.len() >= len {
                     return set;
@@ -108,6 +112,7 @@ fn compute(len: usize) -> Vec<u64> {
             }
         }
         let _ = map.insert(n, pairs);
+        progress::count(map.len() as u64);
     }
 
     unreachable!();
@@ -117,7 +122,12 @@ fn solve() -> String {
     compute(5).into_iter().sum::<u64>().to_string()
 }
 
-common::problem!("26033", solve);
+common::problem!(
+    title: "Prime pair sets",
+    tags: &["primes", "graph"],
+    crates: &["prime"];
+    "26033", solve
+);
 
 #[cfg(test)]
 mod tests {
@@ -112,7 +112,12 @@ fn solve() -> String {
         .to_string()
 }
 
-common::problem!("28684", solve);
+common::problem!(
+    title: "Cyclical figurate numbers",
+    tags: &["figurate-numbers"],
+    crates: &["iter"];
+    "28684", solve
+);
 
 #[cfg(test)]
 mod tests {
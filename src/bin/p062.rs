@@ -56,7 +56,12 @@ fn solve() -> String {
     perm_exact(5).iter().min().unwrap().to_string()
 }
 
-common::problem!("127035954683", solve);
+common::problem!(
+    title: "Cubic permutations",
+    tags: &["digits", "permutations"],
+    crates: &["integer"];
+    "127035954683", solve
+);
 
 #[cfg(test)]
 mod tests {
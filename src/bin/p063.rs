@@ -32,4 +32,9 @@ fn solve() -> String {
     cnt.to_string()
 }
 
-common::problem!("49", solve);
+common::problem!(
+    title: "Powerful digit counts",
+    tags: &["digits"],
+    crates: &["num-bigint", "num-traits"];
+    "49", solve
+);
@@ -20,4 +20,9 @@ fn solve() -> String {
         .to_string()
 }
 
-common::problem!("1322", solve);
+common::problem!(
+    title: "Odd period square roots",
+    tags: &["continued-fractions"],
+    crates: &["cont_frac", "num-integer"];
+    "1322", solve
+);
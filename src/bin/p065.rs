@@ -30,4 +30,9 @@ fn solve() -> String {
         .to_string()
 }
 
-common::problem!("272", solve);
+common::problem!(
+    title: "Convergents of e",
+    tags: &["continued-fractions", "bignum"],
+    crates: &["cont_frac", "num-bigint"];
+    "272", solve
+);
@@ -23,4 +23,9 @@ fn solve() -> String {
         .to_string()
 }
 
-common::problem!("661", solve);
+common::problem!(
+    title: "Diophantine equation",
+    tags: &["continued-fractions", "pell-equation"],
+    crates: &["cont_frac", "iter", "num-bigint"];
+    "661", solve
+);
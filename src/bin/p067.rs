@@ -37,4 +37,9 @@ fn solve(file: File) -> io::Result<String> {
     Ok(ans.to_string())
 }
 
-common::problem!("7273", "p067_triangle.txt", solve);
+common::problem!(
+    title: "Maximum path sum II",
+    tags: &["dp"],
+    crates: &[];
+    "7273", "p067_triangle.txt", solve
+);
@@ -29,4 +29,9 @@ fn solve() -> String {
     "6531031914842725".to_string()
 }
 
-common::problem!("6531031914842725", solve);
+common::problem!(
+    title: "Magic 5-gon ring",
+    tags: &["permutations", "search"],
+    crates: &[];
+    "6531031914842725", solve
+);
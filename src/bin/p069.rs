@@ -27,7 +27,12 @@ fn solve() -> String {
     compute(1000000).to_string()
 }
 
-common::problem!("510510", solve);
+common::problem!(
+    title: "Totient maximum",
+    tags: &["totient"],
+    crates: &["prime"];
+    "510510", solve
+);
 
 #[cfg(test)]
 mod tests {
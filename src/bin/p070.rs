@@ -60,4 +60,9 @@ fn solve() -> String {
     compute(10000000).to_string()
 }
 
-common::problem!("8319823", solve);
+common::problem!(
+    title: "Totient permutation",
+    tags: &["totient", "permutations"],
+    crates: &["integer", "prime"];
+    "8319823", solve
+);
@@ -30,7 +30,12 @@ fn solve() -> String {
     compute(1000000).to_string()
 }
 
-common::problem!("428570", solve);
+common::problem!(
+    title: "Ordered fractions",
+    tags: &["fractions"],
+    crates: &[];
+    "428570", solve
+);
 
 #[cfg(test)]
 mod tests {
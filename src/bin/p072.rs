@@ -9,6 +9,7 @@
     unused_results
 )]
 
+use common::Params;
 use prime::PrimeSet;
 
 fn compute(limit: u64) -> u64 {
@@ -29,11 +30,16 @@ fn compute(limit: u64) -> u64 {
     v.into_iter().sum()
 }
 
-fn solve() -> String {
-    compute(1000000).to_string()
+fn solve(params: &Params) -> String {
+    compute(params.get("limit")).to_string()
 }
 
-common::problem!("303963552391", solve);
+common::problem!(
+    title: "Counting fractions",
+    tags: &["totient", "sieve"],
+    crates: &["prime"];
+    "303963552391", solve, limit: u64 = 1_000_000
+);
 
 #[cfg(test)]
 mod tests {
@@ -21,7 +21,12 @@ fn solve() -> String {
     count_between((1, 3), (1, 2), 12000).to_string()
 }
 
-common::problem!("7295372", solve);
+common::problem!(
+    title: "Counting fractions in a range",
+    tags: &["fractions"],
+    crates: &[];
+    "7295372", solve
+);
 
 #[cfg(test)]
 mod tests {
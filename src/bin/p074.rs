@@ -95,7 +95,12 @@ fn solve() -> String {
     cnt.to_string()
 }
 
-common::problem!("402", solve);
+common::problem!(
+    title: "Digit factorial chains",
+    tags: &["digits", "memoization"],
+    crates: &[];
+    "402", solve
+);
 
 #[cfg(test)]
 mod tests {
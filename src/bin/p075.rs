@@ -28,4 +28,9 @@ fn solve() -> String {
     v.iter().filter(|&x| x == &1).count().to_string()
 }
 
-common::problem!("161667", solve);
+common::problem!(
+    title: "Singular integer right triangles",
+    tags: &["pythagorean-triples"],
+    crates: &["integer", "seq"];
+    "161667", solve
+);
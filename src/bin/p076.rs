@@ -33,7 +33,12 @@ fn solve() -> String {
     count_way(100).to_string()
 }
 
-common::problem!("190569291", solve);
+common::problem!(
+    title: "Counting summations",
+    tags: &["partitions", "dp"],
+    crates: &[];
+    "190569291", solve
+);
 
 #[cfg(test)]
 mod tests {
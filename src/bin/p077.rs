@@ -59,7 +59,12 @@ fn solve() -> String {
         .to_string()
 }
 
-common::problem!("71", solve);
+common::problem!(
+    title: "Prime summations",
+    tags: &["partitions", "primes"],
+    crates: &["prime"];
+    "71", solve
+);
 
 #[cfg(test)]
 mod tests {
@@ -52,4 +52,9 @@ fn solve() -> String {
     unreachable!()
 }
 
-common::problem!("55374", solve);
+common::problem!(
+    title: "Coin partitions",
+    tags: &["partitions"],
+    crates: &[];
+    "55374", solve
+);
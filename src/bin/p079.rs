@@ -31,4 +31,9 @@ fn solve(file: File) -> io::Result<String> {
     Ok(s)
 }
 
-common::problem!("73162890", "p079_keylog.txt", solve);
+common::problem!(
+    title: "Passcode derivation",
+    tags: &["graph"],
+    crates: &["topological-sort"];
+    "73162890", "p079_keylog.txt", solve
+);
@@ -56,7 +56,12 @@ fn solve() -> String {
         .to_string()
 }
 
-common::problem!("40886", solve);
+common::problem!(
+    title: "Square root digital expansion",
+    tags: &["bignum", "digits"],
+    crates: &["iter", "num-bigint", "num-traits"];
+    "40886", solve
+);
 
 #[cfg(test)]
 mod test {
@@ -50,7 +50,12 @@ fn solve(file: File) -> io::Result<String> {
     Ok(minimal_path_sum(mat).to_string())
 }
 
-common::problem!("427337", "p081_matrix.txt", solve);
+common::problem!(
+    title: "Path sum: two ways",
+    tags: &["dp"],
+    crates: &[];
+    "427337", "p081_matrix.txt", solve
+);
 
 #[cfg(test)]
 mod tests {
@@ -66,7 +66,12 @@ fn solve(file: File) -> io::Result<String> {
     Ok(minimal_path_sum(mat).to_string())
 }
 
-common::problem!("260324", "p082_matrix.txt", solve);
+common::problem!(
+    title: "Path sum: three ways",
+    tags: &["dp"],
+    crates: &[];
+    "260324", "p082_matrix.txt", solve
+);
 
 #[cfg(test)]
 mod tests {
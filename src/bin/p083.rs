@@ -126,7 +126,12 @@ fn solve(file: File) -> io::Result<String> {
     Ok(minimal_path_sum(mat).to_string())
 }
 
-common::problem!("425185", "p083_matrix.txt", solve);
+common::problem!(
+    title: "Path sum: four ways",
+    tags: &["graph", "shortest-path"],
+    crates: &[];
+    "425185", "p083_matrix.txt", solve
+);
 
 #[cfg(test)]
 mod tests {
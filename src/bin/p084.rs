@@ -265,7 +265,12 @@ fn solve() -> String {
     )
 }
 
-common::problem!("101524", solve);
+common::problem!(
+    title: "Monopoly odds",
+    tags: &["probability"],
+    crates: &["derive-try-from-primitive", "generic-matrix"];
+    "101524", solve
+);
 
 #[cfg(test)]
 mod tests {
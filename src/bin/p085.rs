@@ -60,4 +60,9 @@ fn solve() -> String {
     (x * y).to_string()
 }
 
-common::problem!("2772", solve);
+common::problem!(
+    title: "Counting rectangles",
+    tags: &["combinatorics"],
+    crates: &[];
+    "2772", solve
+);
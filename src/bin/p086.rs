@@ -64,7 +64,12 @@ fn solve() -> String {
     get_min_m(1000000).to_string()
 }
 
-common::problem!("1818", solve);
+common::problem!(
+    title: "Cuboid route",
+    tags: &["pythagorean-triples"],
+    crates: &["seq"];
+    "1818", solve
+);
 
 #[cfg(test)]
 mod tests {
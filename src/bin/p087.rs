@@ -51,7 +51,12 @@ fn solve() -> String {
     compute(50000000).to_string()
 }
 
-common::problem!("1097343", solve);
+common::problem!(
+    title: "Prime power triples",
+    tags: &["primes"],
+    crates: &["prime"];
+    "1097343", solve
+);
 
 #[cfg(test)]
 mod tests {
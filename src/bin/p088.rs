@@ -9,6 +9,7 @@
     unused_results
 )]
 
+use common::progress;
 use num_integer::Integer;
 use std::u32;
 
@@ -56,6 +57,7 @@ fn compute(limit: usize) -> u32 {
     let mut cnt = limit - 1;
     let mut nums = vec![u32::MAX; limit + 1];
 
+    let search = common::span("search");
     while cnt > 0 {
         let end = start * 2;
         each_product_sum_number(start, end, &mut |n, len| {
@@ -67,7 +69,11 @@ fn compute(limit: usize) -> u32 {
             }
         });
         start *= 2;
+        progress::fraction((limit - 1 - cnt) as f64 / (limit - 1) as f64);
     }
+    drop(search);
+
+    let _t = common::span("sum");
 
     nums.sort();
     nums.dedup();
@@ -81,7 +87,12 @@ fn solve() -> String {
     compute(12000).to_string()
 }
 
-common::problem!("7587457", solve);
+common::problem!(
+    title: "Product-sum numbers",
+    tags: &["factorization", "search"],
+    crates: &["num-integer"];
+    "7587457", solve
+);
 
 #[cfg(test)]
 mod tests {
@@ -121,7 +121,12 @@ fn solve(file: File) -> io::Result<String> {
     Ok(sum.to_string())
 }
 
-common::problem!("743", "p089_roman.txt", solve);
+common::problem!(
+    title: "Roman numerals",
+    tags: &["roman-numerals"],
+    crates: &[];
+    "743", "p089_roman.txt", solve
+);
 
 #[cfg(test)]
 mod tests {
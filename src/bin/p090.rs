@@ -48,4 +48,9 @@ fn solve() -> String {
     cnt.to_string()
 }
 
-common::problem!("1217", solve);
+common::problem!(
+    title: "Cube digit pairs",
+    tags: &["combinatorics"],
+    crates: &["iter"];
+    "1217", solve
+);
@@ -38,7 +38,12 @@ fn solve() -> String {
     compute(50, 50).to_string()
 }
 
-common::problem!("14234", solve);
+common::problem!(
+    title: "Right triangles with integer coordinates",
+    tags: &["geometry"],
+    crates: &["num-integer"];
+    "14234", solve
+);
 
 #[cfg(test)]
 mod tests {
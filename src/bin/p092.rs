@@ -46,7 +46,12 @@ fn solve() -> String {
         .to_string()
 }
 
-common::problem!("8581146", solve);
+common::problem!(
+    title: "Square digit chains",
+    tags: &["digits", "memoization"],
+    crates: &["integer"];
+    "8581146", solve
+);
 
 #[cfg(test)]
 mod tests {
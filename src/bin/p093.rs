@@ -164,7 +164,12 @@ fn solve() -> String {
     format!("{}{}{}{}", seq[0], seq[1], seq[2], seq[3])
 }
 
-common::problem!("1258", solve);
+common::problem!(
+    title: "Arithmetic expressions",
+    tags: &["fractions", "search"],
+    crates: &["iter", "num-rational", "num-traits"];
+    "1258", solve
+);
 
 #[cfg(test)]
 mod tests {
@@ -76,4 +76,9 @@ fn solve() -> String {
         .to_string()
 }
 
-common::problem!("518408346", solve);
+common::problem!(
+    title: "Almost equilateral triangles",
+    tags: &["geometry", "pell-equation"],
+    crates: &["cont_frac", "num-bigint", "num-traits"];
+    "518408346", solve
+);
@@ -9,6 +9,8 @@
     unused_results
 )]
 
+use common::Params;
+
 fn get_chain_len(mut n: usize, len_map: &mut [Option<usize>], div_map: &[usize]) -> usize {
     if let Some(x) = len_map[n] {
         return x;
@@ -66,8 +68,13 @@ fn compute(limit: usize) -> usize {
         .0
 }
 
-fn solve() -> String {
-    compute(1000000).to_string()
+fn solve(params: &Params) -> String {
+    compute(params.get("limit")).to_string()
 }
 
-common::problem!("14316", solve);
+common::problem!(
+    title: "Amicable chains",
+    tags: &["divisors", "sieve"],
+    crates: &[];
+    "14316", solve, limit: usize = 1_000_000
+);
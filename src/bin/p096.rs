@@ -204,4 +204,9 @@ fn solve(file: File) -> io::Result<String> {
     Ok(sum.to_string())
 }
 
-common::problem!("24702", "p096_sudoku.txt", solve);
+common::problem!(
+    title: "Su Doku",
+    tags: &["sudoku", "search"],
+    crates: &[];
+    "24702", "p096_sudoku.txt", solve
+);
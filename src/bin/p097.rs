@@ -53,4 +53,9 @@ fn solve() -> String {
     .to_string()
 }
 
-common::problem!("8739992577", solve);
+common::problem!(
+    title: "Large non-Mersenne prime",
+    tags: &["modular-arithmetic"],
+    crates: &["num-bigint", "num-traits"];
+    "8739992577", solve
+);
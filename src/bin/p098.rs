@@ -172,4 +172,9 @@ fn solve(file: File) -> io::Result<String> {
     Ok(max.to_string())
 }
 
-common::problem!("18769", "p098_words.txt", solve);
+common::problem!(
+    title: "Anagramic squares",
+    tags: &["words", "squares"],
+    crates: &["integer"];
+    "18769", "p098_words.txt", solve
+);
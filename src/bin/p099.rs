@@ -33,4 +33,9 @@ fn solve(file: File) -> io::Result<String> {
     Ok(max_idx.to_string())
 }
 
-common::problem!("709", "p099_base_exp.txt", solve);
+common::problem!(
+    title: "Largest exponential",
+    tags: &["logarithms"],
+    crates: &[];
+    "709", "p099_base_exp.txt", solve
+);
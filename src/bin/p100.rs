@@ -38,7 +38,12 @@ fn solve() -> String {
     compute(limit).to_string()
 }
 
-common::problem!("756872327473", solve);
+common::problem!(
+    title: "Arranged probability",
+    tags: &["pell-equation", "probability"],
+    crates: &["cont_frac", "num-bigint", "num-integer", "num-traits"];
+    "756872327473", solve
+);
 
 #[cfg(test)]
 mod tests {
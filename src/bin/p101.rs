@@ -70,7 +70,12 @@ fn solve() -> String {
         .to_string()
 }
 
-common::problem!("37076114526", solve);
+common::problem!(
+    title: "Optimum polynomial",
+    tags: &["polynomials"],
+    crates: &["num-bigint", "num-rational", "num-traits", "polynomial"];
+    "37076114526", solve
+);
 
 #[cfg(test)]
 mod tests {
@@ -65,7 +65,7 @@ impl Line {
 
 impl Triangle {
     fn contains(self, p: Point) -> bool {
-        use crate::Side::{C, L, R};
+        use self::Side::{C, L, R};
         let s0 = Line(self.0, self.1).side(p);
         let s1 = Line(self.1, self.2).side(p);
         let s2 = Line(self.2, self.0).side(p);
@@ -113,7 +113,12 @@ fn solve(file: File) -> io::Result<String> {
     Ok(cnt.to_string())
 }
 
-common::problem!("228", "p102_triangles.txt", solve);
+common::problem!(
+    title: "Triangle containment",
+    tags: &["geometry"],
+    crates: &[];
+    "228", "p102_triangles.txt", solve
+);
 
 #[cfg(test)]
 mod test {
@@ -154,7 +154,12 @@ fn solve() -> String {
         .concat()
 }
 
-common::problem!("20313839404245", solve);
+common::problem!(
+    title: "Special subset sums: optimum",
+    tags: &["sets", "search"],
+    crates: &[];
+    "20313839404245", solve
+);
 
 #[cfg(test)]
 mod tests {
@@ -117,7 +117,12 @@ fn solve() -> String {
     (k + 1).to_string()
 }
 
-common::problem!("329468", solve);
+common::problem!(
+    title: "Pandigital Fibonacci ends",
+    tags: &["fibonacci", "pandigital"],
+    crates: &["num-bigint", "num-integer", "seq"];
+    "329468", solve
+);
 
 #[cfg(test)]
 mod tests {
@@ -76,4 +76,9 @@ fn solve(file: File) -> io::Result<String> {
     Ok(sum.to_string())
 }
 
-common::problem!("73702", "p105_sets.txt", solve);
+common::problem!(
+    title: "Special subset sums: testing",
+    tags: &["sets"],
+    crates: &[];
+    "73702", "p105_sets.txt", solve
+);
@@ -109,7 +109,12 @@ fn solve() -> String {
     get_num_pairs(&PrimeSet::new(), 12).to_string()
 }
 
-common::problem!("21384", solve);
+common::problem!(
+    title: "Special subset sums: meta-testing",
+    tags: &["sets", "combinatorics"],
+    crates: &["prime"];
+    "21384", solve
+);
 
 #[cfg(test)]
 mod tests {
@@ -48,7 +48,12 @@ fn solve(file: File) -> io::Result<String> {
     Ok(compute(file, 40)?.to_string())
 }
 
-common::problem!("259679", "p107_network.txt", solve);
+common::problem!(
+    title: "Minimal network",
+    tags: &["graph", "minimum-spanning-tree"],
+    crates: &["union-find"];
+    "259679", "p107_network.txt", solve
+);
 
 #[cfg(test)]
 mod tests {
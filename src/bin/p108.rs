@@ -34,7 +34,12 @@ fn solve() -> String {
     (1..).find(|&i| num_pairs(&ps, i) > n).unwrap().to_string()
 }
 
-common::problem!("180180", solve);
+common::problem!(
+    title: "Diophantine reciprocals I",
+    tags: &["diophantine", "divisors"],
+    crates: &["prime"];
+    "180180", solve
+);
 
 #[cfg(test)]
 mod tests {
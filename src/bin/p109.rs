@@ -53,7 +53,12 @@ fn solve() -> String {
     count_way(100).to_string()
 }
 
-common::problem!("38182", solve);
+common::problem!(
+    title: "Darts",
+    tags: &["combinatorics"],
+    crates: &["polynomial"];
+    "38182", solve
+);
 
 #[cfg(test)]
 mod tests {
@@ -57,7 +57,12 @@ fn solve() -> String {
     compute(4000000).to_string()
 }
 
-common::problem!("9350130049860600", solve);
+common::problem!(
+    title: "Diophantine reciprocals II",
+    tags: &["diophantine", "divisors"],
+    crates: &["prime"];
+    "9350130049860600", solve
+);
 
 #[cfg(test)]
 mod tests {
@@ -130,7 +130,12 @@ fn solve() -> String {
         .to_string()
 }
 
-common::problem!("612407567715", solve);
+common::problem!(
+    title: "Primes with runs",
+    tags: &["primes", "digits"],
+    crates: &["integer", "iter", "prime"];
+    "612407567715", solve
+);
 
 #[cfg(test)]
 mod tests {
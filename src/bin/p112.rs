@@ -78,7 +78,12 @@ fn solve() -> String {
     compute(99).to_string()
 }
 
-common::problem!("1587000", solve);
+common::problem!(
+    title: "Bouncy numbers",
+    tags: &["digits"],
+    crates: &["integer"];
+    "1587000", solve
+);
 
 #[cfg(test)]
 mod tests {
@@ -57,7 +57,12 @@ fn solve() -> String {
     num_nonbouncy(100).to_string()
 }
 
-common::problem!("51161058134250", solve);
+common::problem!(
+    title: "Non-bouncy numbers",
+    tags: &["digits", "combinatorics"],
+    crates: &[];
+    "51161058134250", solve
+);
 
 #[cfg(test)]
 mod tests {
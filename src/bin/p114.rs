@@ -45,7 +45,12 @@ fn solve() -> String {
     get_cnt((50, 3), &mut map).to_string()
 }
 
-common::problem!("16475640049", solve);
+common::problem!(
+    title: "Counting block combinations I",
+    tags: &["dp"],
+    crates: &[];
+    "16475640049", solve
+);
 
 #[cfg(test)]
 mod tests {
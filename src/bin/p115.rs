@@ -48,7 +48,12 @@ fn solve() -> String {
         .to_string()
 }
 
-common::problem!("168", solve);
+common::problem!(
+    title: "Counting block combinations II",
+    tags: &["dp"],
+    crates: &[];
+    "168", solve
+);
 
 #[cfg(test)]
 mod tests {
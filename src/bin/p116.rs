@@ -49,7 +49,12 @@ fn solve() -> String {
     count_all(50, &mut map).to_string()
 }
 
-common::problem!("20492570929", solve);
+common::problem!(
+    title: "Red, green or blue tiles",
+    tags: &["dp"],
+    crates: &[];
+    "20492570929", solve
+);
 
 #[cfg(test)]
 mod tests {
@@ -39,7 +39,12 @@ fn solve() -> String {
     count(50, &mut map).to_string()
 }
 
-common::problem!("100808458960497", solve);
+common::problem!(
+    title: "Red, green, and blue tiles",
+    tags: &["dp"],
+    crates: &[];
+    "100808458960497", solve
+);
 
 #[cfg(test)]
 mod tests {
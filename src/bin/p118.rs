@@ -9,6 +9,7 @@
     unused_results
 )]
 
+use common::progress;
 use integer::Integer;
 use iter::{BitCombination, Permutations};
 use num_integer::Integer as NumInteger;
@@ -60,7 +61,8 @@ impl<T: Clone> Iterator for Groups<T> {
     }
 }
 
-fn count_primes(ps: &PrimeSet, digits: &[u64]) -> usize {
+// Reports the progress by the size of the first group if `is_top` is `true`.
+fn count_primes(ps: &PrimeSet, digits: &[u64], is_top: bool) -> usize {
     if digits.is_empty() {
         return 1;
     }
@@ -75,6 +77,7 @@ fn count_primes(ps: &PrimeSet, digits: &[u64]) -> usize {
                 continue;
             }
 
+            let primes = common::span("primes");
             let num_prime = if ds.len() == 1 {
                 if ps.contains(ds[0]) {
                     1
@@ -91,12 +94,16 @@ fn count_primes(ps: &PrimeSet, digits: &[u64]) -> usize {
             } else {
                 0
             };
+            drop(primes);
 
             if num_prime != 0 {
-                let rest_primes = count_primes(ps, &rest);
+                let rest_primes = count_primes(ps, &rest, false);
                 cnt += num_prime * rest_primes;
             }
         }
+        if is_top {
+            progress::fraction(n as f64 / digits.len() as f64);
+        }
     }
     cnt
 }
@@ -104,10 +111,15 @@ fn count_primes(ps: &PrimeSet, digits: &[u64]) -> usize {
 fn solve() -> String {
     let digits = (1..10).collect::<Vec<_>>();
     let ps = PrimeSet::new();
-    count_primes(&ps, &digits).to_string()
+    count_primes(&ps, &digits, true).to_string()
 }
 
-common::problem!("44680", solve);
+common::problem!(
+    title: "Pandigital prime sets",
+    tags: &["pandigital", "primes"],
+    crates: &["integer", "iter", "num-integer", "prime"];
+    "44680", solve
+);
 
 #[cfg(test)]
 mod tests {
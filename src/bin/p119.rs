@@ -77,7 +77,12 @@ fn solve() -> String {
     n.to_string()
 }
 
-common::problem!("248155780267521", solve);
+common::problem!(
+    title: "Digit power sum",
+    tags: &["digits"],
+    crates: &["integer"];
+    "248155780267521", solve
+);
 
 #[cfg(test)]
 mod tests {
@@ -56,7 +56,12 @@ fn solve() -> String {
     (3..1001).map(rmax).sum::<u32>().to_string()
 }
 
-common::problem!("333082500", solve);
+common::problem!(
+    title: "Square remainders",
+    tags: &["modular-arithmetic"],
+    crates: &["num-integer"];
+    "333082500", solve
+);
 
 #[cfg(test)]
 mod tests {
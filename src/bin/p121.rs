@@ -60,7 +60,12 @@ fn solve() -> String {
     max_prize(prob).to_string()
 }
 
-common::problem!("2269", solve);
+common::problem!(
+    title: "Disc game prize fund",
+    tags: &["probability", "polynomials"],
+    crates: &["num-bigint", "num-integer", "num-rational", "num-traits", "polynomial"];
+    "2269", solve
+);
 
 #[cfg(test)]
 mod tests {
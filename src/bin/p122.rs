@@ -41,7 +41,12 @@ fn solve() -> String {
         .to_string()
 }
 
-common::problem!("1582", solve);
+common::problem!(
+    title: "Efficient exponentiation",
+    tags: &["search"],
+    crates: &[];
+    "1582", solve
+);
 
 #[cfg(test)]
 mod tests {
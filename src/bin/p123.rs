@@ -41,7 +41,12 @@ fn solve() -> String {
     compute(10u64.pow(10)).to_string()
 }
 
-common::problem!("21035", solve);
+common::problem!(
+    title: "Prime square remainders",
+    tags: &["primes", "modular-arithmetic"],
+    crates: &["num-integer", "prime"];
+    "21035", solve
+);
 
 #[cfg(test)]
 mod tests {
@@ -150,7 +150,12 @@ fn solve() -> String {
         .to_string()
 }
 
-common::problem!("21417", solve);
+common::problem!(
+    title: "Ordered radicals",
+    tags: &["factorization", "sieve"],
+    crates: &["prime"];
+    "21417", solve
+);
 
 #[cfg(test)]
 mod tests {
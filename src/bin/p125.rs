@@ -42,7 +42,12 @@ fn solve() -> String {
     set.iter().sum::<u32>().to_string()
 }
 
-common::problem!("2906969179", solve);
+common::problem!(
+    title: "Palindromic sums",
+    tags: &["palindromes"],
+    crates: &["integer"];
+    "2906969179", solve
+);
 
 #[cfg(test)]
 mod tests {
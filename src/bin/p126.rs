@@ -54,7 +54,12 @@ fn solve() -> String {
     compute(1000).to_string()
 }
 
-common::problem!("18522", solve);
+common::problem!(
+    title: "Cuboid layers",
+    tags: &["geometry"],
+    crates: &[];
+    "18522", solve
+);
 
 #[cfg(test)]
 mod tests {
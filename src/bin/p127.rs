@@ -9,6 +9,7 @@
     unused_results
 )]
 
+use common::progress;
 use std::cmp::Ordering;
 
 // [定理]
@@ -65,13 +66,22 @@ fn rad_has_union(a: &[u64], b: &[u64]) -> bool {
 }
 
 fn abc_hits_c_sum(c_limit: u64) -> u64 {
+    let rad = common::span("rad");
     let rad_vec = create_rad_vec(c_limit);
     let mut sorted_rad_vec = rad_vec[1..].to_vec(); // drop a == 0 element
     sorted_rad_vec.sort();
+    drop(rad);
 
+    let _t = common::span("search");
     let mut c_sum = 0;
 
+    // Progress is reported only when the percentage changes.
+    let mut percent = 0;
     for c in 3..c_limit {
+        if c * 100 / c_limit != percent {
+            percent = c * 100 / c_limit;
+            progress::fraction(c as f64 / c_limit as f64);
+        }
         let Rad(rad_c, _, ref c_facts) = rad_vec[c as usize];
         if rad_c == c {
             continue;
@@ -101,7 +111,12 @@ fn solve() -> String {
     abc_hits_c_sum(120000).to_string()
 }
 
-common::problem!("18407904", solve);
+common::problem!(
+    title: "abc-hits",
+    tags: &["factorization"],
+    crates: &[];
+    "18407904", solve
+);
 
 #[cfg(test)]
 mod tests {
@@ -242,7 +242,12 @@ fn solve() -> String {
     Pd3Nums::new().nth(2000 - 1).unwrap().to_string()
 }
 
-common::problem!("14516824220", solve);
+common::problem!(
+    title: "Hexagonal tile differences",
+    tags: &["primes"],
+    crates: &["prime"];
+    "14516824220", solve
+);
 
 #[cfg(test)]
 mod tests {
@@ -38,7 +38,12 @@ fn solve() -> String {
         .to_string()
 }
 
-common::problem!("1000023", solve);
+common::problem!(
+    title: "Repunit divisibility",
+    tags: &["repunits", "modular-arithmetic"],
+    crates: &["num-bigint", "num-integer", "num-traits"];
+    "1000023", solve
+);
 
 #[cfg(test)]
 mod tests {
@@ -40,7 +40,12 @@ fn solve() -> String {
         .to_string()
 }
 
-common::problem!("149253", solve);
+common::problem!(
+    title: "Composites with prime repunit property",
+    tags: &["repunits", "primes"],
+    crates: &["num-bigint", "num-integer", "num-traits", "prime"];
+    "149253", solve
+);
 
 #[cfg(test)]
 mod tests {
@@ -109,7 +109,12 @@ fn solve() -> String {
     compute(1000000).to_string()
 }
 
-common::problem!("173", solve);
+common::problem!(
+    title: "Prime cube partnership",
+    tags: &["primes"],
+    crates: &["prime"];
+    "173", solve
+);
 
 #[cfg(test)]
 mod tests {
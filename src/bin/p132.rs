@@ -39,4 +39,9 @@ pub fn solve() -> String {
         .to_string()
 }
 
-common::problem!("843296", solve);
+common::problem!(
+    title: "Large repunit factors",
+    tags: &["repunits", "primes"],
+    crates: &["integer", "prime"];
+    "843296", solve
+);
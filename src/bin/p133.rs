@@ -86,4 +86,9 @@ fn solve() -> String {
     (sum + 2 + 3 + 5).to_string()
 }
 
-common::problem!("453647705", solve);
+common::problem!(
+    title: "Repunit nonfactors",
+    tags: &["repunits", "primes"],
+    crates: &["prime"];
+    "453647705", solve
+);
@@ -136,4 +136,9 @@ fn solve() -> String {
     sum.to_string()
 }
 
-common::problem!("18613426663617118", solve);
+common::problem!(
+    title: "Prime pair connection",
+    tags: &["primes", "modular-arithmetic"],
+    crates: &["num-integer", "prime"];
+    "18613426663617118", solve
+);
@@ -100,7 +100,12 @@ fn solve() -> String {
         .to_string()
 }
 
-common::problem!("4989", solve);
+common::problem!(
+    title: "Same differences",
+    tags: &["diophantine"],
+    crates: &[];
+    "4989", solve
+);
 
 #[cfg(test)]
 mod tests {
@@ -39,7 +39,12 @@ fn solve() -> String {
         .to_string()
 }
 
-common::problem!("2544559", solve);
+common::problem!(
+    title: "Singleton difference",
+    tags: &["diophantine"],
+    crates: &[];
+    "2544559", solve
+);
 
 #[cfg(test)]
 mod tests {
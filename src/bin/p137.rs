@@ -64,7 +64,12 @@ fn solve() -> String {
     compute(14).to_string()
 }
 
-common::problem!("1120149658760", solve);
+common::problem!(
+    title: "Fibonacci golden nuggets",
+    tags: &["fibonacci", "pell-equation"],
+    crates: &["itertools"];
+    "1120149658760", solve
+);
 
 #[cfg(test)]
 mod tests {
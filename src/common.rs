@@ -7,47 +7,290 @@
     unused_results
 )]
 
-use attohttpc::StatusCode;
-use failure::Fail;
+use crate::{data::setup_file, metadata::Metadata, profile::Span};
+use failure::{bail, Fail};
 use getopts::Options;
 use num_integer::Integer;
 use serde::{Deserialize, Serialize};
 use std::{
+    any::Any,
+    backtrace::{Backtrace, BacktraceStatus},
     borrow::Cow,
+    cell::{Cell, RefCell},
     env, fmt,
-    fs::{self, File},
+    fs::File,
     io,
     io::prelude::*,
+    panic::{self, AssertUnwindSafe},
     path::PathBuf,
     process,
+    str::FromStr,
+    sync::Once,
     time::Instant,
 };
 use term::{color, color::Color};
 
+pub mod answer;
+pub mod data;
+pub mod metadata;
+pub mod profile;
+pub mod progress;
+
+pub use crate::profile::span;
+
 type OutputPair<'a> = (Option<Color>, Cow<'a, str>);
 
 const NSEC_PER_SEC: u128 = 1000000000;
 const NSEC_WARN_LIMIT: u128 = NSEC_PER_SEC;
 const NSEC_NG_LIMIT: u128 = 10 * NSEC_PER_SEC;
 
+const STDIN_PATH: &str = "/dev/stdin";
+
 const COLOR_OK: Color = color::GREEN;
 const COLOR_NG: Color = color::RED;
 const COLOR_WARN: Color = color::YELLOW;
 
-#[derive(Fail, Debug, Clone)]
-#[fail(display = "{}, {}", status, body)]
-struct InvalidHttpStatusError {
-    status: StatusCode,
-    body: String,
-}
-
 pub type Result<T> = std::result::Result<T, failure::Error>;
+type SolveResult<T> = std::result::Result<T, SolverError>;
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct SolverResult<T> {
     pub time: u128,
+    /// Answer of the solver. Hashed like `expected` unless answers are revealed.
     pub answer: T,
+    /// Correct answer of the problem, if known. May be hashed, see `answer`.
+    #[serde(default)]
+    pub expected: Option<T>,
     pub is_ok: bool,
+    /// `true` if the answer is not compared with the expected one, because the
+    /// solver was given custom inputs. `is_ok` is `true` in that case.
+    #[serde(default)]
+    pub is_unchecked: bool,
+    /// `true` if the solver was killed because it exceeded the time limit.
+    #[serde(default)]
+    pub is_timeout: bool,
+    /// Peak resident set size of the solver process in bytes, if measured.
+    #[serde(default)]
+    pub max_rss: Option<u64>,
+    /// Statistics of the repeated runs in benchmark mode. `time` is their median.
+    #[serde(default)]
+    pub stats: Option<BenchStats>,
+    /// Name of the strategy that produced the answer, if the problem has several.
+    #[serde(default)]
+    pub strategy: Option<String>,
+    /// Why the problem was not solved correctly, if it was not.
+    #[serde(default)]
+    pub error: Option<SolverError>,
+    /// Timing spans recorded by the solver. Averaged over the runs in
+    /// benchmark mode.
+    #[serde(default)]
+    pub spans: Vec<Span>,
+}
+
+impl SolverResult<String> {
+    /// Creates the result of a problem which could not be solved.
+    pub fn from_error(error: SolverError) -> SolverResult<String> {
+        SolverResult {
+            is_ok: false,
+            error: Some(error),
+            ..Default::default()
+        }
+    }
+}
+
+/// Why a problem was not solved correctly. Each class has its own exit code.
+#[derive(Debug, Clone, PartialEq, Fail, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "kebab-case")]
+pub enum SolverError {
+    /// The data file could not be downloaded, verified or opened.
+    #[fail(display = "data error: {}", message)]
+    Data { message: String },
+    /// The solver failed to read or parse the data file.
+    #[fail(display = "parse error: {}", message)]
+    Parse { message: String },
+    /// The solver panicked. The backtrace is captured if enabled by
+    /// `RUST_BACKTRACE` or `RUST_LIB_BACKTRACE`.
+    #[fail(display = "solver panicked: {}", message)]
+    Panic {
+        message: String,
+        #[serde(default)]
+        location: Option<String>,
+        #[serde(default)]
+        backtrace: Option<String>,
+    },
+    /// The answer differs from the expected one.
+    #[fail(display = "wrong answer: expected {}, got {}", expected, answer)]
+    WrongAnswer { expected: String, answer: String },
+}
+
+impl SolverError {
+    /// Returns the name of the class, as in the `kind` field of the JSON.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            SolverError::Data { .. } => "data",
+            SolverError::Parse { .. } => "parse",
+            SolverError::Panic { .. } => "panic",
+            SolverError::WrongAnswer { .. } => "wrong-answer",
+        }
+    }
+
+    /// Returns the exit code of a problem binary failing with this error.
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            SolverError::WrongAnswer { .. } => 1,
+            SolverError::Data { .. } => 2,
+            SolverError::Parse { .. } => 3,
+            SolverError::Panic { .. } => 4,
+        }
+    }
+
+    /// Returns `true` if a problem binary exits with `code` on a `SolverError`.
+    pub fn is_exit_code(code: i32) -> bool {
+        (1..=4).contains(&code)
+    }
+
+    /// Describes the error with the location and the backtrace of a panic.
+    pub fn report(&self) -> String {
+        let mut report = self.to_string();
+        if let SolverError::Panic {
+            ref location,
+            ref backtrace,
+            ..
+        } = *self
+        {
+            if let Some(ref location) = *location {
+                report.push_str(&format!("\n  at {}", location));
+            }
+            if let Some(ref backtrace) = *backtrace {
+                report.push_str(&format!("\nstack backtrace:\n{}", backtrace));
+            }
+        }
+        report
+    }
+}
+
+thread_local! {
+    // `true` while a solver is running on this thread, whose panics are caught.
+    static IS_SOLVING: Cell<bool> = const { Cell::new(false) };
+    // Location and backtrace of the last panic of a solver on this thread.
+    static LAST_PANIC: RefCell<(Option<String>, Option<String>)> =
+        const { RefCell::new((None, None)) };
+}
+
+// Replaces the panic hook with the one recording the panics of the solvers
+// instead of printing them. Other panics are passed to the previous hook.
+fn install_panic_hook() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        let prev = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !IS_SOLVING.with(Cell::get) {
+                prev(info);
+                return;
+            }
+            let location = info.location().map(|l| l.to_string());
+            let backtrace = Backtrace::capture();
+            let backtrace = match backtrace.status() {
+                BacktraceStatus::Captured => Some(backtrace.to_string()),
+                _ => None,
+            };
+            LAST_PANIC.with(|p| *p.borrow_mut() = (location, backtrace));
+        }));
+    });
+}
+
+// Calls `f`, catching its panic as `SolverError::Panic`.
+fn catch_panic<T, F: FnOnce() -> T>(f: F) -> std::result::Result<T, SolverError> {
+    install_panic_hook();
+    IS_SOLVING.with(|s| s.set(true));
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    IS_SOLVING.with(|s| s.set(false));
+    result.map_err(|payload| {
+        let (location, backtrace) = LAST_PANIC.with(|p| p.replace((None, None)));
+        SolverError::Panic {
+            message: panic_message(&*payload),
+            location,
+            backtrace,
+        }
+    })
+}
+
+/// Checks that every strategy of the solver solves the problem, panicking with
+/// the report of the error otherwise. Called by `test_solve` of `problem!`.
+#[doc(hidden)]
+#[track_caller]
+pub fn assert_solved(solver: &Solver<'_>) {
+    let results = solver
+        .solve_all(0, 0)
+        .unwrap_or_else(|e| panic!("{}", e.report()));
+    for r in results {
+        if let Some(e) = r.error {
+            match r.strategy {
+                Some(strategy) => panic!("strategy {}: {}", strategy, e.report()),
+                None => panic!("{}", e.report()),
+            }
+        }
+        assert!(r.is_ok);
+    }
+}
+
+/// Returns the message of a panic caught by `panic::catch_unwind`.
+pub fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        s.to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "(non-string panic payload)".to_string()
+    }
+}
+
+/// Statistics of the solving times of repeated runs, in nanoseconds.
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BenchStats {
+    pub runs: usize,
+    pub min: u128,
+    pub median: u128,
+    pub mean: u128,
+    pub stddev: u128,
+    pub max: u128,
+}
+
+impl BenchStats {
+    /// Computes the statistics of the samples. The median of an even number of
+    /// samples is the mean of the two middle ones.
+    pub fn from_samples(samples: &[u128]) -> BenchStats {
+        assert!(!samples.is_empty());
+
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let runs = sorted.len();
+        let median = (sorted[(runs - 1) / 2] + sorted[runs / 2]) / 2;
+        let mean = sorted.iter().sum::<u128>() / runs as u128;
+        let var = sorted
+            .iter()
+            .map(|&t| {
+                let d = t as f64 - mean as f64;
+                d * d
+            })
+            .sum::<f64>()
+            / runs as f64;
+
+        BenchStats {
+            runs,
+            min: sorted[0],
+            median,
+            mean,
+            stddev: var.sqrt() as u128,
+            max: sorted[runs - 1],
+        }
+    }
+}
+
+/// Formats nanoseconds as seconds with nine decimal places.
+pub fn format_time(nsec: u128) -> String {
+    let (sec, nsec) = nsec.div_rem(&NSEC_PER_SEC);
+    format!("{:3}.{:09}", sec, nsec)
 }
 
 impl<T: Serialize> SolverResult<T> {
@@ -90,15 +333,24 @@ impl<T: fmt::Display> SolverResult<T> {
         items.push(normal(format!("{} ", name)));
 
         items.push(normal("["));
-        if self.is_ok {
+        let error = match self.error {
+            Some(SolverError::WrongAnswer { .. }) | None => None,
+            Some(ref e) => Some(e),
+        };
+        if self.is_unchecked {
+            items.push(normal("UNCHECKED"));
+        } else if self.is_ok {
             items.push(ok("OK"));
+        } else if self.is_timeout {
+            items.push(ng("TIMEOUT"));
+        } else if let Some(e) = error {
+            items.push(ng(e.kind().to_uppercase()));
         } else {
             items.push(ng("NG"));
         }
         items.push(normal("] "));
 
-        let (sec, nsec) = self.time.div_rem(&NSEC_PER_SEC);
-        let time_str = format!("{:3}.{:09} ", sec, nsec);
+        let time_str = format!("{} ", format_time(self.time));
         if !enable_time_color || self.time < NSEC_WARN_LIMIT {
             items.push(normal(time_str));
         } else if self.time < NSEC_NG_LIMIT {
@@ -107,9 +359,54 @@ impl<T: fmt::Display> SolverResult<T> {
             items.push(ng(time_str));
         }
 
-        items.push(normal(format!("{} ", self.answer)));
+        if let Some(ref st) = self.stats {
+            items.push(normal(format!(
+                "(min {} / mean {} \u{b1} {} / max {}, {} runs) ",
+                format_time(st.min).trim_start(),
+                format_time(st.mean).trim_start(),
+                format_time(st.stddev).trim_start(),
+                format_time(st.max).trim_start(),
+                st.runs
+            )));
+        }
+
+        let answer = self.answer.to_string();
+        if let Some(e) = error {
+            items.push(normal(format!("{} ", e.report())));
+        } else if answer::is_hashed(&answer) {
+            items.push(normal("(hidden, --reveal to show) "));
+        } else {
+            items.push(normal(format!("{} ", answer)));
+        }
 
         items.push(normal("\n"));
+
+        // Spans are listed as a tree below the name.
+        let spans = self.spans.iter().flat_map(Span::walk).collect::<Vec<_>>();
+        let width = spans
+            .iter()
+            .map(|&(depth, span)| 2 * depth + span.name.len())
+            .max()
+            .unwrap_or(0);
+        for (depth, span) in spans {
+            let label = format!("{:indent$}{}", "", span.name, indent = 2 * depth);
+            let mut line = format!(
+                "{:indent$}{:<width$} {}",
+                "",
+                label,
+                format_time(span.time),
+                indent = name.len() + 1,
+                width = width
+            );
+            if self.time > 0 {
+                let share = span.time as f64 / self.time as f64 * 100.0;
+                line.push_str(&format!(" {:5.1}%", share));
+            }
+            if span.calls > 1 {
+                line.push_str(&format!(" ({} calls)", span.calls));
+            }
+            items.push(normal(line + "\n"));
+        }
         print_items(&items);
 
         fn normal<'a, T: Into<Cow<'a, str>>>(s: T) -> OutputPair<'a> {
@@ -129,41 +426,281 @@ impl<T: fmt::Display> SolverResult<T> {
     }
 }
 
+/// Checks that a value of a parameter can be parsed as its declared type.
+pub type ParamCheckFn = fn(&str) -> bool;
+
+/// Returns `true` if `s` can be parsed as `T`. Used as the `ParamCheckFn` of a
+/// parameter of type `T`.
+pub fn parses_as<T: FromStr>(s: &str) -> bool {
+    s.parse::<T>().is_ok()
+}
+
+/// Named parameters of a solver, which can be changed by `--param NAME=VALUE`.
+#[derive(Clone, Debug, Default)]
+pub struct Params {
+    values: Vec<(String, String)>,
+    checks: Vec<ParamCheckFn>,
+}
+
+impl Params {
+    /// Creates parameters with the default values and the checks of their values.
+    pub fn new(defaults: &[(&str, String, ParamCheckFn)]) -> Params {
+        Params {
+            values: defaults
+                .iter()
+                .map(|(name, value, _)| (name.to_string(), value.clone()))
+                .collect(),
+            checks: defaults.iter().map(|&(_, _, check)| check).collect(),
+        }
+    }
+
+    /// Returns the value of the parameter.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the parameter is not declared or its value cannot be parsed as `T`,
+    /// which happens only if `T` is not the declared type, as `set` checks values.
+    pub fn get<T: FromStr>(&self, name: &str) -> T {
+        let value = match self.values.iter().find(|(n, _)| n == name) {
+            Some((_, value)) => value,
+            None => panic!("undeclared parameter: {}", name),
+        };
+        match value.parse() {
+            Ok(x) => x,
+            Err(_) => panic!("invalid value of parameter {}: {}", name, value),
+        }
+    }
+
+    /// Sets the value of the declared parameter.
+    ///
+    /// Fails if the parameter is not declared or the value cannot be parsed as
+    /// its declared type.
+    pub fn set(&mut self, name: &str, value: &str) -> Result<()> {
+        let idx = match self.values.iter().position(|(n, _)| n == name) {
+            Some(idx) => idx,
+            None => bail!("unknown parameter: {}", name),
+        };
+        if !(self.checks[idx])(value) {
+            bail!("invalid value of parameter {}: {}", name, value);
+        }
+        self.values[idx].1 = value.to_string();
+        Ok(())
+    }
+
+    /// Returns the names and values of the parameters in the declared order.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.values.iter().map(|(n, v)| (&n[..], &v[..]))
+    }
+}
+
+#[allow(clippy::enum_variant_names)]
+#[derive(Copy, Clone)]
 enum SolverFn<'a> {
     FnOnly(fn() -> String),
-    FnWithFile(&'a str, fn(File) -> io::Result<String>),
+    FnWithFile(&'a str, FileSolverFn),
+    FnWithParams(fn(&Params) -> String),
 }
 
+/// Solver reading a data file.
+pub type FileSolverFn = fn(File) -> io::Result<String>;
+
+/// Named implementation of a solver.
+pub type Strategy<'a, F> = (&'a str, F);
+
+/// Name of the strategy of a solver created without naming its strategies.
+pub const DEFAULT_STRATEGY: &str = "default";
+
 pub struct Solver<'a> {
     answer: &'a str,
-    solver: SolverFn<'a>,
+    /// Named implementations of the solver. The first one is used by default.
+    strategies: Vec<(&'a str, SolverFn<'a>)>,
+    strategy: usize,
+    defaults: Params,
+    params: Params,
+    input: Option<PathBuf>,
+    metadata: Metadata,
 }
 
 impl<'a> Solver<'a> {
     pub fn new(answer: &'a str, solver: fn() -> String) -> Solver<'a> {
         Solver {
             answer,
-            solver: SolverFn::FnOnly(solver),
+            strategies: vec![(DEFAULT_STRATEGY, SolverFn::FnOnly(solver))],
+            strategy: 0,
+            defaults: Params::default(),
+            params: Params::default(),
+            input: None,
+            metadata: Metadata::default(),
+        }
+    }
+
+    /// Creates a solver with several named strategies, whose answers must agree.
+    pub fn new_with_strategies(
+        answer: &'a str,
+        strategies: &[Strategy<'a, fn() -> String>],
+    ) -> Solver<'a> {
+        assert!(!strategies.is_empty());
+        Solver {
+            strategies: strategies
+                .iter()
+                .map(|&(name, fun)| (name, SolverFn::FnOnly(fun)))
+                .collect(),
+            ..Solver::new(answer, strategies[0].1)
+        }
+    }
+
+    pub fn new_with_file(answer: &'a str, file_name: &'a str, solver: FileSolverFn) -> Solver<'a> {
+        Solver {
+            answer,
+            strategies: vec![(DEFAULT_STRATEGY, SolverFn::FnWithFile(file_name, solver))],
+            strategy: 0,
+            defaults: Params::default(),
+            params: Params::default(),
+            input: None,
+            metadata: Metadata::default(),
         }
     }
 
-    pub fn new_with_file(
+    /// Creates a solver reading a file with several named strategies.
+    pub fn new_with_file_strategies(
         answer: &'a str,
         file_name: &'a str,
-        solver: fn(File) -> io::Result<String>,
+        strategies: &[Strategy<'a, FileSolverFn>],
+    ) -> Solver<'a> {
+        assert!(!strategies.is_empty());
+        Solver {
+            strategies: strategies
+                .iter()
+                .map(|&(name, fun)| (name, SolverFn::FnWithFile(file_name, fun)))
+                .collect(),
+            ..Solver::new_with_file(answer, file_name, strategies[0].1)
+        }
+    }
+
+    /// Creates a solver taking named parameters. The answer is checked only if
+    /// all parameters have the default values.
+    pub fn new_with_params(
+        answer: &'a str,
+        defaults: &[(&str, String, ParamCheckFn)],
+        solver: fn(&Params) -> String,
     ) -> Solver<'a> {
         Solver {
             answer,
-            solver: SolverFn::FnWithFile(file_name, solver),
+            strategies: vec![(DEFAULT_STRATEGY, SolverFn::FnWithParams(solver))],
+            strategy: 0,
+            defaults: Params::new(defaults),
+            params: Params::new(defaults),
+            input: None,
+            metadata: Metadata::default(),
+        }
+    }
+
+    /// Sets the value of a parameter declared by `new_with_params`.
+    pub fn set_param(&mut self, name: &str, value: &str) -> Result<()> {
+        self.params.set(name, value)
+    }
+
+    /// Sets the metadata of the problem.
+    pub fn with_metadata(self, metadata: Metadata) -> Solver<'a> {
+        Solver { metadata, ..self }
+    }
+
+    pub fn metadata(&self) -> &Metadata {
+        &self.metadata
+    }
+
+    /// Returns the names of the strategies.
+    pub fn strategies(&self) -> impl Iterator<Item = &'a str> + '_ {
+        self.strategies.iter().map(|&(name, _)| name)
+    }
+
+    /// Selects the strategy used by `solve` and `bench`.
+    pub fn set_strategy(&mut self, name: &str) -> Result<()> {
+        match self.strategies.iter().position(|&(n, _)| n == name) {
+            Some(idx) => {
+                self.strategy = idx;
+                Ok(())
+            }
+            None => bail!("unknown strategy: {}", name),
+        }
+    }
+
+    /// Makes the solver read `path` instead of its data file, or the standard
+    /// input if `path` is `-`. The answer is not checked then.
+    pub fn set_input<P: Into<PathBuf>>(&mut self, path: P) -> Result<()> {
+        if self.file_name().is_none() {
+            bail!("the solver does not read a file");
+        }
+        self.input = Some(path.into());
+        Ok(())
+    }
+
+    fn is_checked(&self) -> bool {
+        self.params.iter().eq(self.defaults.iter()) && self.input.is_none()
+    }
+
+    fn open_file(&self, file_name: &str) -> SolveResult<File> {
+        let file = match self.input {
+            Some(ref path) if path.as_os_str() == "-" => File::open(STDIN_PATH).map_err(Into::into),
+            Some(ref path) => {
+                File::open(path).map_err(|e| failure::err_msg(format!("{}: {}", path.display(), e)))
+            }
+            None => setup_file(file_name),
+        };
+        file.map_err(|e| SolverError::Data {
+            message: e.to_string(),
+        })
+    }
+
+    /// Returns the name of the data file the solver reads, if any.
+    pub fn file_name(&self) -> Option<&'a str> {
+        match self.strategies[0].1 {
+            SolverFn::FnWithFile(file_name, _) => Some(file_name),
+            _ => None,
         }
     }
 
-    pub fn run(self) {
+    pub fn run(mut self) {
         let args = env::args().collect::<Vec<_>>();
         let program = &args[0];
 
         let mut opts = Options::new();
         let _ = opts.optflag("", "json", "Output JSON format");
+        let _ = opts.optopt("", "bench", "Solve N times and report statistics", "N");
+        let _ = opts.optopt(
+            "",
+            "warmup",
+            "Solve N times before benchmarking (default: 1)",
+            "N",
+        );
+        let _ = opts.optmulti(
+            "",
+            "param",
+            "Set the parameter NAME to VALUE. The answer is not checked then",
+            "NAME=VALUE",
+        );
+        if self.file_name().is_some() {
+            let _ = opts.optopt(
+                "",
+                "input",
+                "Read PATH, or the standard input if PATH is -, instead of the data file. \
+                 The answer is not checked then",
+                "PATH",
+            );
+        }
+        let _ = opts.optopt(
+            "",
+            "strategy",
+            "Solve with the strategy NAME instead of the first one",
+            "NAME",
+        );
+        let _ = opts.optflag(
+            "",
+            "all-strategies",
+            "Solve with every strategy, check that the answers agree and compare the times",
+        );
+        let _ = opts.optflag("", "reveal", "Print the answer even if it is hashed");
+        data::add_options(&mut opts);
         let _ = opts.optflag("h", "help", "Display this message");
 
         let matches = match opts.parse(&args[1..]) {
@@ -177,89 +714,325 @@ impl<'a> Solver<'a> {
         if matches.opt_present("h") {
             let short = opts.short_usage(&program);
             println!("{}", opts.usage(&short));
+            if self.defaults.iter().next().is_some() {
+                println!("Parameters:");
+                for (name, value) in self.defaults.iter() {
+                    println!("    {} (default: {})", name, value);
+                }
+            }
+            if self.strategies.len() > 1 {
+                println!("Strategies:");
+                for name in self.strategies() {
+                    println!("    {}", name);
+                }
+            }
             return;
         }
+        if let Err(e) = data::apply_options(&matches) {
+            let _ = writeln!(&mut io::stderr(), "{}: {}", program, e);
+            process::exit(255);
+        }
+        if matches.opt_present("reveal") {
+            answer::reveal();
+        }
+        if let Some(name) = matches.opt_str("strategy") {
+            if let Err(e) = self.set_strategy(&name) {
+                let _ = writeln!(&mut io::stderr(), "{}: {}", program, e);
+                process::exit(255);
+            }
+        }
+        let all_strategies = matches.opt_present("all-strategies") && self.strategies.len() > 1;
+        for param in matches.opt_strs("param") {
+            let result = match param.find('=') {
+                Some(idx) => self.set_param(&param[..idx], &param[idx + 1..]),
+                None => Err(failure::err_msg(format!("invalid parameter: {}", param))),
+            };
+            if let Err(e) = result {
+                let _ = writeln!(&mut io::stderr(), "{}: {}", program, e);
+                process::exit(255);
+            }
+        }
 
-        match self.solve() {
-            Err(err) => {
-                let _ = writeln!(&mut io::stderr(), "{}: {}", program, err);
+        let parse_count = |name: &str, default: usize| match matches.opt_str(name) {
+            None => default,
+            Some(s) => match s.parse() {
+                Ok(n) => n,
+                Err(e) => {
+                    let _ = writeln!(&mut io::stderr(), "{}: --{} {}: {}", program, name, s, e);
+                    process::exit(255);
+                }
+            },
+        };
+        let runs = parse_count("bench", 0);
+        let warmup = parse_count("warmup", 1);
+
+        // `--input` is defined only for the solvers reading a file.
+        let input = match self.file_name() {
+            Some(_) => matches.opt_str("input"),
+            None => None,
+        };
+        if let Some(path) = input {
+            if path == "-" && (runs + warmup > 1 || all_strategies) {
+                let _ = writeln!(
+                    &mut io::stderr(),
+                    "{}: the standard input can be read only once, \
+                     use --input PATH with --bench or --all-strategies",
+                    program
+                );
                 process::exit(255);
             }
-            Ok(result) => {
-                if matches.opt_present("json") {
+            let _ = self.set_input(path);
+        }
+
+        progress::start(matches.opt_present("json"));
+        if all_strategies {
+            self.run_all(program, runs, warmup, matches.opt_present("json"));
+            return;
+        }
+
+        let result = if runs > 0 {
+            self.bench(runs, warmup)
+        } else {
+            self.solve()
+        };
+        progress::finish();
+        let result = result.unwrap_or_else(SolverResult::from_error);
+        if matches.opt_present("json") {
+            let _ = result.print_json(&mut io::stdout());
+        } else {
+            let _ = result.print_pretty(&program, true);
+        }
+        if let Some(ref e) = result.error {
+            process::exit(e.exit_code());
+        }
+    }
+
+    fn run_all(&self, program: &str, runs: usize, warmup: usize, json: bool) {
+        let results = self.solve_all(runs, warmup);
+        progress::finish();
+        let results = match results {
+            Ok(results) => results,
+            Err(err) => {
+                let result = SolverResult::from_error(err);
+                if json {
                     let _ = result.print_json(&mut io::stdout());
                 } else {
-                    let _ = result.print_pretty(&program, true);
-                }
-                if !result.is_ok {
-                    process::exit(1);
+                    let _ = result.print_pretty(program, true);
                 }
+                process::exit(result.error.map_or(255, |e| e.exit_code()));
+            }
+        };
+        for (name, result) in self.strategies().zip(&results) {
+            if json {
+                let _ = result.print_json(&mut io::stdout());
+            } else {
+                let _ = result.print_pretty(&format!("{}:{}", program, name), true);
             }
         }
+
+        let agree = results.iter().all(|r| r.answer == results[0].answer);
+        if !agree {
+            let _ = writeln!(
+                &mut io::stderr(),
+                "{}: the answers of the strategies disagree",
+                program
+            );
+        } else if !json {
+            let (fastest, min) = self
+                .strategies()
+                .zip(&results)
+                .min_by_key(|(_, r)| r.time)
+                .map(|(name, r)| (name, r.time.max(1)))
+                .unwrap();
+            let others = self
+                .strategies()
+                .zip(&results)
+                .filter(|&(name, _)| name != fastest)
+                .map(|(name, r)| format!("{} x{:.2}", name, r.time as f64 / min as f64))
+                .collect::<Vec<_>>();
+            println!(
+                "{}: the answers agree, {} is the fastest ({})",
+                program,
+                fastest,
+                others.join(", ")
+            );
+        }
+        if let Some(e) = results.iter().find_map(|r| r.error.as_ref()) {
+            process::exit(e.exit_code());
+        }
+        if !agree {
+            process::exit(1);
+        }
     }
 
-    pub fn solve(&self) -> Result<SolverResult<String>> {
-        let (time, answer) = match self.solver {
-            SolverFn::FnOnly(fun) => bench(fun),
-            SolverFn::FnWithFile(file_name, fun) => {
-                let file = setup_file(file_name)?;
-                let (time, answer) = bench(move || fun(file));
-                (time, answer?)
-            }
+    fn solve_once(&self, strategy: usize) -> SolveResult<(u128, String, Vec<Span>)> {
+        let file = match self.strategies[strategy].1 {
+            SolverFn::FnWithFile(file_name, _) => Some(self.open_file(file_name)?),
+            _ => None,
         };
+        profile::start();
+        let solved = catch_panic(|| match self.strategies[strategy].1 {
+            SolverFn::FnOnly(fun) => Ok(bench(fun)),
+            SolverFn::FnWithFile(_, fun) => {
+                let (time, answer) = bench(move || fun(file.unwrap()));
+                answer.map(|answer| (time, answer))
+            }
+            SolverFn::FnWithParams(fun) => Ok(bench(|| fun(&self.params))),
+        });
+        let spans = profile::finish();
+        solved?
+            .map(|(time, answer)| (time, answer, spans))
+            .map_err(|e| SolverError::Parse {
+                message: e.to_string(),
+            })
+    }
 
-        let result = SolverResult {
-            is_ok: answer == self.answer,
+    // Reports a panic of the solver as a failed result.
+    fn catch_failure(
+        &self,
+        strategy: usize,
+        result: SolveResult<SolverResult<String>>,
+    ) -> SolveResult<SolverResult<String>> {
+        match result {
+            Err(e @ SolverError::Panic { .. }) => Ok(SolverResult {
+                strategy: self.strategy_name(strategy),
+                ..SolverResult::from_error(e)
+            }),
+            result => result,
+        }
+    }
+
+    // Results name the strategy only if the solver has several of them.
+    fn strategy_name(&self, strategy: usize) -> Option<String> {
+        if self.strategies.len() > 1 {
+            Some(self.strategies[strategy].0.to_string())
+        } else {
+            None
+        }
+    }
+
+    fn result(&self, strategy: usize, time: u128, answer: String) -> SolverResult<String> {
+        let strategy = self.strategy_name(strategy);
+        if !self.is_checked() {
+            return SolverResult {
+                time,
+                answer,
+                is_ok: true,
+                is_unchecked: true,
+                strategy,
+                ..Default::default()
+            };
+        }
+        let is_ok = answer::check(self.answer, &answer);
+        let answer = answer::conceal(self.answer, answer);
+        let error = if is_ok {
+            None
+        } else {
+            Some(SolverError::WrongAnswer {
+                expected: self.answer.to_string(),
+                answer: answer.clone(),
+            })
+        };
+        SolverResult {
+            is_ok,
             time,
             answer,
-        };
-        Ok(result)
+            expected: Some(self.answer.to_string()),
+            strategy,
+            error,
+            ..Default::default()
+        }
     }
-}
 
-fn bench<T, F: FnOnce() -> T>(f: F) -> (u128, T) {
-    let start_time = Instant::now();
-    let result = f();
-    let nsec = start_time.elapsed().as_nanos();
-    (nsec, result)
-}
+    pub fn solve(&self) -> SolveResult<SolverResult<String>> {
+        self.solve_strategy(self.strategy)
+    }
 
-fn setup_file(file_name: &str) -> Result<File> {
-    let mut path = PathBuf::from("./.cache");
-    path.push(file_name);
-    if !path.is_file() {
-        fs::create_dir_all(&path.parent().unwrap())?;
-        let content = download(file_name)?;
-        File::create(&path)?.write_all(&content)?;
+    fn solve_strategy(&self, strategy: usize) -> SolveResult<SolverResult<String>> {
+        let result = self
+            .solve_once(strategy)
+            .map(|(time, answer, spans)| SolverResult {
+                spans,
+                ..self.result(strategy, time, answer)
+            });
+        self.catch_failure(strategy, result)
     }
 
-    let file = File::open(&path)?;
-    Ok(file)
-}
+    /// Solves `warmup` times without measuring, and then `runs` times measuring each time.
+    pub fn bench(&self, runs: usize, warmup: usize) -> SolveResult<SolverResult<String>> {
+        self.bench_strategy(self.strategy, runs, warmup)
+    }
+
+    fn bench_strategy(
+        &self,
+        strategy: usize,
+        runs: usize,
+        warmup: usize,
+    ) -> SolveResult<SolverResult<String>> {
+        let result = self.bench_runs(strategy, runs, warmup);
+        self.catch_failure(strategy, result)
+    }
 
-const BASE_URL: &str = "https://projecteuler.net/project/resources/";
-fn download(file_name: &str) -> Result<Vec<u8>> {
-    let url = format!("{}{}", BASE_URL, file_name);
+    fn bench_runs(
+        &self,
+        strategy: usize,
+        runs: usize,
+        warmup: usize,
+    ) -> SolveResult<SolverResult<String>> {
+        assert!(runs > 0);
 
-    for retry in 0.. {
-        let resp = attohttpc::get(&url).send()?;
-        let status = resp.status();
-        let body = resp.bytes()?;
-        if status.is_success() {
-            return Ok(body);
+        for _ in 0..warmup {
+            let _ = self.solve_once(strategy)?;
         }
 
-        let err = InvalidHttpStatusError {
-            status,
-            body: String::from_utf8_lossy(&body).into(),
-        };
-        let program = env::args().next().unwrap();
-        let _ = writeln!(&mut io::stderr(), "{}: {}", program, err);
-        if retry >= 3 {
-            return Err(err.into());
+        // The first wrong answer is reported, if any.
+        let mut times = vec![];
+        let mut is_ok = true;
+        let mut answer = String::new();
+        let mut spans = vec![];
+        for _ in 0..runs {
+            let (time, ans, run_spans) = self.solve_once(strategy)?;
+            times.push(time);
+            for span in run_spans {
+                profile::merge(&mut spans, span);
+            }
+            if is_ok {
+                is_ok = self.result(strategy, time, ans.clone()).is_ok;
+                answer = ans;
+            }
+        }
+
+        for span in &mut spans {
+            span.divide(runs);
         }
+        let stats = BenchStats::from_samples(&times);
+        let result = SolverResult {
+            stats: Some(stats),
+            spans,
+            ..self.result(strategy, stats.median, answer)
+        };
+        Ok(result)
+    }
+
+    /// Solves with every strategy, benchmarking if `runs` is not zero.
+    pub fn solve_all(&self, runs: usize, warmup: usize) -> SolveResult<Vec<SolverResult<String>>> {
+        (0..self.strategies.len())
+            .map(|i| {
+                if runs > 0 {
+                    self.bench_strategy(i, runs, warmup)
+                } else {
+                    self.solve_strategy(i)
+                }
+            })
+            .collect()
     }
-    unreachable!();
+}
+
+fn bench<T, F: FnOnce() -> T>(f: F) -> (u128, T) {
+    let start_time = Instant::now();
+    let result = f();
+    let nsec = start_time.elapsed().as_nanos();
+    (nsec, result)
 }
 
 pub fn init() {
@@ -269,33 +1042,270 @@ pub fn init() {
     pretty_env_logger::init();
 }
 
+/// Defines `solver`, `main` and `test_solve` of a problem. The answer may be a
+/// salted hash created by `euler hash-answers`, see `answer`.
+///
+/// Several strategies can be given as `name => solver`, such as
+/// `problem!("142913828922", sieve => solve, naive => solve_naive)`. The first
+/// one is the default, and `test_solve` checks all of them.
+///
+/// Named parameters can be given after the solver as `name: type = default`,
+/// such as `problem!("837799", solve, limit: u64 = 1_000_000)`. Values given by
+/// `--param` are rejected unless they parse as the type.
+///
+/// Metadata can be given before the answer as `key: value` pairs, followed by
+/// `;`. See `metadata::Metadata` for the keys.
 #[macro_export]
 macro_rules! problem {
-    ($answer:expr, $solver:expr) => {
+    ($($key:ident : $value:expr),+ ; $($rest:tt)*) => {
+        $crate::problem!(@new $crate::metadata::Metadata::default()$(.$key($value))+; $($rest)*);
+    };
+    (@solver $meta:expr, $new:expr) => {
+        /// Returns the solver of this problem. The `euler` runner calls this to
+        /// solve the problem in its own process.
+        pub fn solver() -> $crate::Solver<'static> {
+            $new.with_metadata($meta)
+        }
+
+        #[allow(dead_code)]
         fn main() {
             $crate::init();
-            $crate::Solver::new($answer, $solver).run();
+            solver().run();
         }
 
         #[test]
         fn test_solve() {
-            assert!($crate::Solver::new($answer, $solver).solve().unwrap().is_ok);
+            $crate::assert_solved(&solver());
         }
     };
-    ($answer:expr, $file:expr, $solver:expr) => {
-        fn main() {
-            $crate::init();
-            $crate::Solver::new_with_file($answer, $file, $solver).run();
+    (@new $meta:expr; $answer:expr, $($name:ident => $solver:expr),+ $(,)*) => {
+        $crate::problem!(@solver $meta, $crate::Solver::new_with_strategies(
+            $answer,
+            &[$((stringify!($name), $solver as fn() -> String)),+]
+        ));
+    };
+    (@new $meta:expr; $answer:expr, $file:expr, $($name:ident => $solver:expr),+ $(,)*) => {
+        $crate::problem!(@solver $meta, $crate::Solver::new_with_file_strategies(
+            $answer,
+            $file,
+            &[$((stringify!($name), $solver as $crate::FileSolverFn)),+]
+        ));
+    };
+    (@new $meta:expr; $answer:expr, $solver:expr) => {
+        $crate::problem!(@solver $meta, $crate::Solver::new($answer, $solver));
+    };
+    (@new $meta:expr; $answer:expr, $solver:expr, $($name:ident: $ty:ty = $default:expr),+ $(,)*) => {
+        $crate::problem!(@solver $meta, $crate::Solver::new_with_params(
+            $answer,
+            &[$((
+                stringify!($name),
+                { let default: $ty = $default; default.to_string() },
+                $crate::parses_as::<$ty> as $crate::ParamCheckFn
+            )),+],
+            $solver
+        ));
+    };
+    (@new $meta:expr; $answer:expr, $file:expr, $solver:expr) => {
+        $crate::problem!(@solver $meta, $crate::Solver::new_with_file($answer, $file, $solver));
+    };
+    ($($rest:tt)*) => {
+        $crate::problem!(@new $crate::metadata::Metadata::default(); $($rest)*);
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parses_as, BenchStats, ParamCheckFn, Params, Solver, SolverError};
+    use std::{env, fs, fs::File, io, io::prelude::*};
+
+    fn sum_below(params: &Params) -> String {
+        (1..params.get::<u32>("limit")).sum::<u32>().to_string()
+    }
+
+    fn limit(default: &str) -> [(&'static str, String, ParamCheckFn); 1] {
+        [("limit", default.to_string(), parses_as::<u32>)]
+    }
+
+    #[test]
+    fn params() {
+        let mut params = Params::new(&[
+            ("limit", "10".to_string(), parses_as::<u32>),
+            ("name", "x".to_string(), parses_as::<String>),
+        ]);
+        assert_eq!(10, params.get::<u32>("limit"));
+        assert_eq!("x", params.get::<String>("name"));
+        params.set("limit", "20").unwrap();
+        assert_eq!(20, params.get::<u32>("limit"));
+        assert!(params.set("bound", "20").is_err());
+        assert_eq!(
+            "invalid value of parameter limit: abc",
+            params.set("limit", "abc").unwrap_err().to_string()
+        );
+        assert!(params.set("limit", "-1").is_err());
+        assert_eq!(20, params.get::<u32>("limit"));
+        assert_eq!(
+            vec![("limit", "20"), ("name", "x")],
+            params.iter().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid value of parameter limit: 10")]
+    fn get_undeclared_type() {
+        let params = Params::new(&limit("10"));
+        let _ = params.get::<bool>("limit");
+    }
+
+    #[test]
+    fn solve_with_params() {
+        let mut solver = Solver::new_with_params("45", &limit("10"), sum_below);
+        let r = solver.solve().unwrap();
+        assert!(r.is_ok && !r.is_unchecked);
+
+        solver.set_param("limit", "5").unwrap();
+        let r = solver.solve().unwrap();
+        assert!(r.is_ok && r.is_unchecked);
+        assert_eq!("10", r.answer);
+        assert_eq!(None, r.expected);
+
+        solver.set_param("limit", "10").unwrap();
+        assert!(!solver.bench(2, 0).unwrap().is_unchecked);
+        assert!(solver.set_param("limit", "abc").is_err());
+        assert!(!solver.solve().unwrap().is_unchecked);
+    }
+
+    fn count_lines(file: File) -> io::Result<String> {
+        Ok(io::BufReader::new(file).lines().count().to_string())
+    }
+
+    #[test]
+    fn solve_with_input() {
+        let path = env::temp_dir().join(format!("euler-input-{}.txt", std::process::id()));
+        fs::write(&path, "a\nb\n").unwrap();
+
+        let mut solver = Solver::new_with_file("3", "unused.txt", count_lines);
+        solver.set_input(&path).unwrap();
+        let r = solver.solve().unwrap();
+        assert!(r.is_ok && r.is_unchecked);
+        assert_eq!("2", r.answer);
+
+        let _ = fs::remove_file(&path);
+        assert!(solver.solve().is_err());
+
+        let mut solver = Solver::new("0", || "0".to_string());
+        assert!(solver.set_input("-").is_err());
+    }
+
+    #[test]
+    fn strategies() {
+        fn right() -> String {
+            "45".to_string()
+        }
+        fn wrong() -> String {
+            "44".to_string()
         }
+        let mut solver = Solver::new_with_strategies("45", &[("right", right), ("wrong", wrong)]);
+        assert_eq!(
+            vec!["right", "wrong"],
+            solver.strategies().collect::<Vec<_>>()
+        );
 
-        #[test]
-        fn test_solve() {
-            assert!(
-                $crate::Solver::new_with_file($answer, $file, $solver)
-                    .solve()
-                    .unwrap()
-                    .is_ok
-            );
+        let results = solver.solve_all(0, 0).unwrap();
+        assert_eq!(
+            vec![(Some("right"), true), (Some("wrong"), false)],
+            results
+                .iter()
+                .map(|r| (r.strategy.as_deref(), r.is_ok))
+                .collect::<Vec<_>>()
+        );
+
+        assert!(solver.solve().unwrap().is_ok);
+        solver.set_strategy("wrong").unwrap();
+        assert!(!solver.solve().unwrap().is_ok);
+        assert!(solver.set_strategy("naive").is_err());
+
+        let solver = Solver::new("45", right);
+        assert_eq!(None, solver.solve().unwrap().strategy);
+    }
+
+    #[test]
+    fn errors() {
+        fn wrong() -> String {
+            "44".to_string()
         }
-    };
+        fn panics() -> String {
+            panic!("overflow at {}", 10)
+        }
+        fn unparsable(_: File) -> io::Result<String> {
+            Err(io::Error::new(io::ErrorKind::InvalidData, "bad number"))
+        }
+
+        let r = Solver::new("45", wrong).solve().unwrap();
+        assert_eq!(
+            Some(SolverError::WrongAnswer {
+                expected: "45".to_string(),
+                answer: "44".to_string()
+            }),
+            r.error
+        );
+        assert_eq!(1, r.error.unwrap().exit_code());
+
+        let r = Solver::new("45", panics).solve().unwrap();
+        assert!(!r.is_ok);
+        let e = r.error.unwrap();
+        match e {
+            SolverError::Panic {
+                ref message,
+                ref location,
+                ..
+            } => {
+                assert_eq!("overflow at 10", message);
+                assert!(location.as_ref().unwrap().starts_with("src/common.rs:"));
+            }
+            _ => panic!("unexpected error: {:?}", e),
+        }
+        assert_eq!((4, "panic"), (e.exit_code(), e.kind()));
+        assert!(e.report().contains("\n  at src/common.rs:"));
+
+        let path = env::temp_dir().join(format!("euler-parse-{}.txt", std::process::id()));
+        fs::write(&path, "x").unwrap();
+        let mut solver = Solver::new_with_file("45", "unused.txt", unparsable);
+        solver.set_input(&path).unwrap();
+        let e = solver.solve().unwrap_err();
+        assert_eq!("parse error: bad number", e.to_string());
+        assert_eq!(3, e.exit_code());
+
+        let _ = fs::remove_file(&path);
+        let e = solver.solve().unwrap_err();
+        assert_eq!((2, "data"), (e.exit_code(), e.kind()));
+        assert!(SolverError::is_exit_code(e.exit_code()));
+
+        let json = serde_json::to_string(&e).unwrap();
+        assert!(json.starts_with(r#"{"kind":"data","message":"#));
+        assert_eq!(e, serde_json::from_str(&json).unwrap());
+    }
+
+    #[test]
+    fn bench_stats() {
+        let st = BenchStats::from_samples(&[5, 1, 3]);
+        assert_eq!(
+            BenchStats {
+                runs: 3,
+                min: 1,
+                median: 3,
+                mean: 3,
+                stddev: 1,
+                max: 5,
+            },
+            st
+        );
+
+        let st = BenchStats::from_samples(&[4, 1, 3, 8]);
+        assert_eq!(3, st.median);
+        assert_eq!(4, st.mean);
+        assert_eq!(2, st.stddev);
+
+        let st = BenchStats::from_samples(&[7]);
+        assert_eq!((7, 7, 7, 0), (st.min, st.median, st.max, st.stddev));
+    }
 }
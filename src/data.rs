@@ -0,0 +1,667 @@
+//! Data files of problems.
+//!
+//! Files are read from the data directory, and downloaded from the Project Euler
+//! server if they are missing. The locations and the retry policy of downloads
+//! can be changed by environment variables, which the options added by
+//! `add_options` set.
+//!
+//! Files are verified against the SHA-256 digests and sizes in the manifest
+//! `src/data/manifest.txt`, both when they are downloaded and when they are read.
+
+use crate::Result;
+use attohttpc::StatusCode;
+use failure::{bail, Fail};
+use getopts::{Matches, Options};
+use sha2::{Digest, Sha256};
+use std::{
+    env, fmt,
+    fs::{self, File},
+    io,
+    io::prelude::*,
+    path::PathBuf,
+    thread,
+    time::Duration,
+};
+
+/// Environment variable of the directory data files are read from.
+pub const DATA_DIR_ENV: &str = "EULER_DATA_DIR";
+/// Environment variable of the URL data files are downloaded from.
+pub const BASE_URL_ENV: &str = "EULER_BASE_URL";
+/// Environment variable that disables downloading if set to a value other than `0`.
+pub const OFFLINE_ENV: &str = "EULER_OFFLINE";
+/// Environment variable of the maximum number of attempts to download a file.
+pub const DOWNLOAD_ATTEMPTS_ENV: &str = "EULER_DOWNLOAD_ATTEMPTS";
+/// Environment variable of the timeout of each download attempt, in seconds.
+pub const DOWNLOAD_TIMEOUT_ENV: &str = "EULER_DOWNLOAD_TIMEOUT";
+
+const MANIFEST: &str = include_str!("data/manifest.txt");
+const MANIFEST_HEADER: &str = "\
+# SHA-256 digests and sizes in bytes of the data files, one `<sha256> <size> <file name>` per line.
+# Files not listed here are not verified.
+# Regenerate with `euler verify-data --write-manifest src/data/manifest.txt` from files known to be good.";
+
+const DEFAULT_DATA_DIR: &str = "./.cache";
+const DEFAULT_BASE_URL: &str = "https://projecteuler.net/project/resources/";
+
+/// Error of downloading a data file.
+#[derive(Fail, Debug, Clone)]
+#[fail(
+    display = "failed to download {} from {} after {} attempt(s): {}",
+    file_name, url, attempts, kind
+)]
+pub struct DownloadError {
+    pub file_name: String,
+    pub url: String,
+    pub attempts: u32,
+    pub kind: DownloadErrorKind,
+}
+
+/// Cause of the last failed attempt of a download.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DownloadErrorKind {
+    /// The server responded with an unsuccessful status.
+    Status { status: StatusCode, body: String },
+    /// No response was received, or its body could not be read.
+    Transport(String),
+}
+
+impl fmt::Display for DownloadErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            DownloadErrorKind::Status {
+                ref status,
+                ref body,
+            } => write!(f, "{}, {}", status, body),
+            DownloadErrorKind::Transport(ref e) => write!(f, "{}", e),
+        }
+    }
+}
+
+/// Policy of retrying failed downloads.
+///
+/// Transport errors, server errors and `408`/`429` responses are retried. Other
+/// failures, such as `404`, are not.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct RetryPolicy {
+    /// Maximum number of attempts, including the first one.
+    pub attempts: u32,
+    /// Delay before the first retry. The delay doubles on each further retry.
+    pub backoff: Duration,
+    /// Upper bound of the delay between attempts.
+    pub max_backoff: Duration,
+    /// Timeout of connecting, and of each attempt after connecting.
+    pub timeout: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> RetryPolicy {
+        RetryPolicy {
+            attempts: 4,
+            backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(30),
+            timeout: Duration::from_secs(30),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Returns the delay before the `retry`-th retry, counted from zero.
+    pub fn delay(&self, retry: u32) -> Duration {
+        let factor = 1u32.checked_shl(retry).unwrap_or(u32::MAX);
+        match self.backoff.checked_mul(factor) {
+            Some(d) if d < self.max_backoff => d,
+            _ => self.max_backoff,
+        }
+    }
+}
+
+/// Locations of data files.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DataConfig {
+    /// Directory data files are read from, and downloaded files are saved to.
+    pub dir: PathBuf,
+    /// URL data files are downloaded from. Files are fetched from `base_url` + file name.
+    pub base_url: String,
+    /// `true` if missing files are errors instead of being downloaded.
+    pub offline: bool,
+    pub retry: RetryPolicy,
+}
+
+impl Default for DataConfig {
+    fn default() -> DataConfig {
+        DataConfig {
+            dir: PathBuf::from(DEFAULT_DATA_DIR),
+            base_url: DEFAULT_BASE_URL.to_string(),
+            offline: false,
+            retry: RetryPolicy::default(),
+        }
+    }
+}
+
+fn parse_attempts(s: &str) -> Result<u32> {
+    match s.parse() {
+        Ok(n) if n > 0 => Ok(n),
+        _ => bail!("invalid number of download attempts: {}", s),
+    }
+}
+
+fn parse_timeout(s: &str) -> Result<Duration> {
+    // `try_from_secs_f64` rejects durations which are infinite or too long.
+    let timeout = s
+        .parse::<f64>()
+        .ok()
+        .filter(|&secs| secs > 0.0)
+        .and_then(|secs| Duration::try_from_secs_f64(secs).ok());
+    match timeout {
+        Some(timeout) => Ok(timeout),
+        None => bail!("invalid download timeout: {}", s),
+    }
+}
+
+impl DataConfig {
+    /// Reads the configuration from the environment variables.
+    pub fn from_env() -> Result<DataConfig> {
+        let mut config = DataConfig::default();
+        if let Some(dir) = env::var_os(DATA_DIR_ENV) {
+            config.dir = PathBuf::from(dir);
+        }
+        if let Ok(url) = env::var(BASE_URL_ENV) {
+            config.base_url = url;
+        }
+        if let Some(offline) = env::var_os(OFFLINE_ENV) {
+            config.offline = !offline.is_empty() && offline != "0";
+        }
+        if let Ok(s) = env::var(DOWNLOAD_ATTEMPTS_ENV) {
+            config.retry.attempts = parse_attempts(&s)?;
+        }
+        if let Ok(s) = env::var(DOWNLOAD_TIMEOUT_ENV) {
+            config.retry.timeout = parse_timeout(&s)?;
+        }
+        Ok(config)
+    }
+
+    /// Sets the environment variables, so that `from_env` in this process and
+    /// in child processes returns this configuration.
+    pub fn set_env(&self) {
+        env::set_var(DATA_DIR_ENV, &self.dir);
+        env::set_var(BASE_URL_ENV, &self.base_url);
+        env::set_var(OFFLINE_ENV, if self.offline { "1" } else { "0" });
+        env::set_var(DOWNLOAD_ATTEMPTS_ENV, self.retry.attempts.to_string());
+        env::set_var(
+            DOWNLOAD_TIMEOUT_ENV,
+            self.retry.timeout.as_secs_f64().to_string(),
+        );
+    }
+
+    /// Returns the path of the data file.
+    pub fn path(&self, file_name: &str) -> PathBuf {
+        self.dir.join(file_name)
+    }
+
+    /// Returns the URL the data file is downloaded from.
+    pub fn url(&self, file_name: &str) -> String {
+        format!("{}{}", self.base_url, file_name)
+    }
+}
+
+/// Adds the `--data-dir`, `--base-url`, `--offline`, `--download-attempts` and
+/// `--download-timeout` options.
+pub fn add_options(opts: &mut Options) {
+    let _ = opts.optopt(
+        "",
+        "data-dir",
+        &format!(
+            "Read data files from DIR (default: ${} or {})",
+            DATA_DIR_ENV, DEFAULT_DATA_DIR
+        ),
+        "DIR",
+    );
+    let _ = opts.optopt(
+        "",
+        "base-url",
+        &format!(
+            "Download data files from URL (default: ${} or {})",
+            BASE_URL_ENV, DEFAULT_BASE_URL
+        ),
+        "URL",
+    );
+    let _ = opts.optflag(
+        "",
+        "offline",
+        "Fail instead of downloading missing data files",
+    );
+    let _ = opts.optopt(
+        "",
+        "download-attempts",
+        &format!(
+            "Try downloading a data file at most N times (default: ${} or {})",
+            DOWNLOAD_ATTEMPTS_ENV,
+            RetryPolicy::default().attempts
+        ),
+        "N",
+    );
+    let _ = opts.optopt(
+        "",
+        "download-timeout",
+        &format!(
+            "Give up a download attempt after SECS seconds (default: ${} or {})",
+            DOWNLOAD_TIMEOUT_ENV,
+            RetryPolicy::default().timeout.as_secs()
+        ),
+        "SECS",
+    );
+}
+
+/// Applies the options added by `add_options` to the environment variables.
+pub fn apply_options(matches: &Matches) -> Result<()> {
+    let mut config = DataConfig::from_env()?;
+    if let Some(dir) = matches.opt_str("data-dir") {
+        config.dir = PathBuf::from(dir);
+    }
+    if let Some(url) = matches.opt_str("base-url") {
+        config.base_url = url;
+    }
+    if matches.opt_present("offline") {
+        config.offline = true;
+    }
+    if let Some(s) = matches.opt_str("download-attempts") {
+        config.retry.attempts = parse_attempts(&s)?;
+    }
+    if let Some(s) = matches.opt_str("download-timeout") {
+        config.retry.timeout = parse_timeout(&s)?;
+    }
+    config.set_env();
+    Ok(())
+}
+
+/// Expected digest and size of a data file.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ManifestEntry {
+    pub file_name: String,
+    /// SHA-256 digest in lowercase hexadecimal.
+    pub sha256: String,
+    pub size: u64,
+}
+
+impl ManifestEntry {
+    /// Creates an entry from the content of the file.
+    pub fn new(file_name: &str, content: &[u8]) -> ManifestEntry {
+        ManifestEntry {
+            file_name: file_name.to_string(),
+            sha256: sha256(content),
+            size: content.len() as u64,
+        }
+    }
+}
+
+impl fmt::Display for ManifestEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {} {}", self.sha256, self.size, self.file_name)
+    }
+}
+
+/// Returns the SHA-256 digest of the content in lowercase hexadecimal.
+pub fn sha256(content: &[u8]) -> String {
+    format!("{:x}", Sha256::digest(content))
+}
+
+/// Parses a manifest. Empty lines and lines starting with `#` are ignored.
+pub fn parse_manifest(s: &str) -> Result<Vec<ManifestEntry>> {
+    let mut entries = vec![];
+    for line in s.lines().map(str::trim) {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let mut it = line.split_whitespace();
+        match (it.next(), it.next().map(str::parse), it.next(), it.next()) {
+            (Some(sha256), Some(Ok(size)), Some(file_name), None)
+                if sha256.len() == 64 && sha256.bytes().all(|b| b.is_ascii_hexdigit()) =>
+            {
+                entries.push(ManifestEntry {
+                    file_name: file_name.to_string(),
+                    sha256: sha256.to_ascii_lowercase(),
+                    size,
+                })
+            }
+            _ => bail!("invalid manifest line: {}", line),
+        }
+    }
+    Ok(entries)
+}
+
+/// Writes a manifest of the entries, which `parse_manifest` parses.
+pub fn write_manifest<W: Write>(out: &mut W, entries: &[ManifestEntry]) -> io::Result<()> {
+    writeln!(out, "{}", MANIFEST_HEADER)?;
+    for entry in entries {
+        writeln!(out, "{}", entry)?;
+    }
+    Ok(())
+}
+
+/// Returns the entries of the built-in manifest.
+pub fn manifest() -> Vec<ManifestEntry> {
+    parse_manifest(MANIFEST).expect("invalid built-in manifest")
+}
+
+/// Result of verifying a data file against the manifest.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Verification {
+    /// The file matches the manifest.
+    Ok,
+    /// The file is not listed in the manifest.
+    Unlisted,
+    /// The file differs from the manifest.
+    Mismatch {
+        expected: ManifestEntry,
+        actual: ManifestEntry,
+    },
+}
+
+impl fmt::Display for Verification {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Verification::Ok => write!(f, "OK"),
+            Verification::Unlisted => write!(f, "UNLISTED"),
+            Verification::Mismatch {
+                ref expected,
+                ref actual,
+            } => write!(
+                f,
+                "MISMATCH (expected {} bytes, sha256 {}; actual {} bytes, sha256 {})",
+                expected.size, expected.sha256, actual.size, actual.sha256
+            ),
+        }
+    }
+}
+
+/// Verifies the content of a data file against the manifest entries.
+pub fn verify(manifest: &[ManifestEntry], file_name: &str, content: &[u8]) -> Verification {
+    let expected = match manifest.iter().find(|e| e.file_name == file_name) {
+        Some(e) => e,
+        None => return Verification::Unlisted,
+    };
+    let actual = ManifestEntry::new(file_name, content);
+    if actual == *expected {
+        Verification::Ok
+    } else {
+        Verification::Mismatch {
+            expected: expected.clone(),
+            actual,
+        }
+    }
+}
+
+pub(crate) fn setup_file(file_name: &str) -> Result<File> {
+    let config = DataConfig::from_env()?;
+    let manifest = manifest();
+    let path = config.path(file_name);
+    if path.is_file() {
+        let content = fs::read(&path)?;
+        match verify(&manifest, file_name, &content) {
+            v @ Verification::Mismatch { .. } => {
+                if config.offline {
+                    bail!(
+                        "{} is corrupted and downloading is disabled in offline mode: {}",
+                        path.display(),
+                        v
+                    );
+                }
+                let _ = writeln!(
+                    &mut io::stderr(),
+                    "{} is corrupted, downloading again: {}",
+                    path.display(),
+                    v
+                );
+            }
+            _ => return Ok(File::open(&path)?),
+        }
+    } else if config.offline {
+        bail!(
+            "{} is not found and downloading is disabled in offline mode",
+            path.display()
+        );
+    }
+
+    let url = config.url(file_name);
+    let content = download(file_name, &url, &config.retry)?;
+    match verify(&manifest, file_name, &content) {
+        v @ Verification::Mismatch { .. } => {
+            bail!("downloaded {} does not match the manifest: {}", url, v)
+        }
+        Verification::Unlisted => {
+            let _ = writeln!(
+                &mut io::stderr(),
+                "{} is not in the manifest, saving it unverified",
+                url
+            );
+        }
+        Verification::Ok => {}
+    }
+    fs::create_dir_all(&config.dir)?;
+    File::create(&path)?.write_all(&content)?;
+
+    let file = File::open(&path)?;
+    Ok(file)
+}
+
+fn download(
+    file_name: &str,
+    url: &str,
+    policy: &RetryPolicy,
+) -> std::result::Result<Vec<u8>, DownloadError> {
+    let program = env::args().next().unwrap();
+    let wait = |kind: &DownloadErrorKind, delay: Duration| {
+        let _ = writeln!(
+            &mut io::stderr(),
+            "{}: {}: {}, retrying in {:?}",
+            program,
+            url,
+            kind,
+            delay
+        );
+        thread::sleep(delay);
+    };
+    retry(policy, wait, || fetch(url, policy.timeout)).map_err(|(attempts, kind)| DownloadError {
+        file_name: file_name.to_string(),
+        url: url.to_string(),
+        attempts,
+        kind,
+    })
+}
+
+// Failure of a download attempt, and whether it is worth retrying.
+type AttemptError = (DownloadErrorKind, bool);
+
+fn fetch(url: &str, timeout: Duration) -> std::result::Result<Vec<u8>, AttemptError> {
+    let transport = |e: attohttpc::Error| {
+        let is_retryable = !matches!(
+            *e.kind(),
+            attohttpc::ErrorKind::InvalidBaseUrl
+                | attohttpc::ErrorKind::InvalidUrlHost
+                | attohttpc::ErrorKind::InvalidUrlPort
+        );
+        (DownloadErrorKind::Transport(e.to_string()), is_retryable)
+    };
+
+    let resp = attohttpc::get(url)
+        .connect_timeout(timeout)
+        .timeout(timeout)
+        .send()
+        .map_err(transport)?;
+    let status = resp.status();
+    let body = resp.bytes().map_err(transport)?;
+    if status.is_success() {
+        return Ok(body);
+    }
+
+    let is_retryable = status.is_server_error()
+        || status == StatusCode::REQUEST_TIMEOUT
+        || status == StatusCode::TOO_MANY_REQUESTS;
+    let kind = DownloadErrorKind::Status {
+        status,
+        body: String::from_utf8_lossy(&body).into(),
+    };
+    Err((kind, is_retryable))
+}
+
+// Calls `attempt` until it succeeds, fails with a non-retryable error, or the
+// attempts run out, calling `wait` with the error and the delay between attempts.
+// Returns the number of attempts and the last error on failure.
+fn retry<T, W, F>(
+    policy: &RetryPolicy,
+    mut wait: W,
+    mut attempt: F,
+) -> std::result::Result<T, (u32, DownloadErrorKind)>
+where
+    W: FnMut(&DownloadErrorKind, Duration),
+    F: FnMut() -> std::result::Result<T, AttemptError>,
+{
+    let mut attempts = 0;
+    loop {
+        attempts += 1;
+        match attempt() {
+            Ok(x) => return Ok(x),
+            Err((kind, true)) if attempts < policy.attempts => {
+                wait(&kind, policy.delay(attempts - 1));
+            }
+            Err((kind, _)) => return Err((attempts, kind)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        manifest, parse_manifest, parse_timeout, retry, verify, write_manifest, DataConfig,
+        DownloadErrorKind, ManifestEntry, RetryPolicy, Verification,
+    };
+    use attohttpc::StatusCode;
+    use std::{path::PathBuf, time::Duration};
+
+    #[test]
+    fn backoff() {
+        let policy = RetryPolicy::default();
+        let delays = (0..7)
+            .map(|i| policy.delay(i).as_secs())
+            .collect::<Vec<_>>();
+        assert_eq!(vec![1, 2, 4, 8, 16, 30, 30], delays);
+        assert_eq!(policy.max_backoff, policy.delay(100));
+    }
+
+    #[test]
+    fn timeout() {
+        assert_eq!(Duration::from_millis(1500), parse_timeout("1.5").unwrap());
+        for s in &["0", "-1", "abc", "inf", "NaN", "1e300"] {
+            assert!(parse_timeout(s).is_err(), "{}", s);
+        }
+    }
+
+    #[test]
+    fn retry_until_success() {
+        let policy = RetryPolicy::default();
+        let transport = DownloadErrorKind::Transport("connection reset".to_string());
+        let mut delays = vec![];
+        let mut results = vec![
+            Ok(42),
+            Err((transport.clone(), true)),
+            Err((transport, true)),
+        ];
+        let wait = |_: &DownloadErrorKind, d: Duration| delays.push(d.as_secs());
+        assert_eq!(Ok(42), retry(&policy, wait, || results.pop().unwrap()));
+        assert_eq!(vec![1, 2], delays);
+    }
+
+    #[test]
+    fn retry_gives_up() {
+        let policy = RetryPolicy {
+            attempts: 3,
+            ..Default::default()
+        };
+        let unavailable = DownloadErrorKind::Status {
+            status: StatusCode::SERVICE_UNAVAILABLE,
+            body: "".to_string(),
+        };
+        let mut count = 0;
+        let result = retry::<(), _, _>(
+            &policy,
+            |_, _| {},
+            || {
+                count += 1;
+                Err((unavailable.clone(), true))
+            },
+        );
+        assert_eq!(Err((3, unavailable)), result);
+        assert_eq!(3, count);
+
+        let not_found = DownloadErrorKind::Status {
+            status: StatusCode::NOT_FOUND,
+            body: "".to_string(),
+        };
+        let mut count = 0;
+        let result = retry::<(), _, _>(
+            &policy,
+            |_, _| {},
+            || {
+                count += 1;
+                Err((not_found.clone(), false))
+            },
+        );
+        assert_eq!(Err((1, not_found)), result);
+        assert_eq!(1, count);
+    }
+
+    #[test]
+    fn built_in_manifest() {
+        let _ = manifest();
+    }
+
+    #[test]
+    fn manifest_format() {
+        let entry = ManifestEntry::new("a.txt", b"abc");
+        assert_eq!(
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad 3 a.txt",
+            entry.to_string()
+        );
+        let entries = vec![entry, ManifestEntry::new("b.txt", b"")];
+        let mut text = vec![];
+        write_manifest(&mut text, &entries).unwrap();
+        let text = String::from_utf8(text).unwrap();
+        assert_eq!(entries, parse_manifest(&text).unwrap());
+        assert!(parse_manifest("# comment\n\n").unwrap().is_empty());
+
+        assert!(parse_manifest("abc 3 a.txt").is_err());
+        assert!(parse_manifest(&format!("{} x a.txt", "0".repeat(64))).is_err());
+        assert!(parse_manifest(&format!("{} 3", "0".repeat(64))).is_err());
+    }
+
+    #[test]
+    fn verification() {
+        let manifest = vec![ManifestEntry::new("a.txt", b"abc")];
+        assert_eq!(Verification::Ok, verify(&manifest, "a.txt", b"abc"));
+        assert_eq!(Verification::Unlisted, verify(&manifest, "b.txt", b"abc"));
+        assert_eq!(
+            Verification::Mismatch {
+                expected: manifest[0].clone(),
+                actual: ManifestEntry::new("a.txt", b"<html>"),
+            },
+            verify(&manifest, "a.txt", b"<html>")
+        );
+    }
+
+    #[test]
+    fn location() {
+        let config = DataConfig {
+            dir: PathBuf::from("/srv/euler"),
+            base_url: "http://localhost:8000/".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(
+            PathBuf::from("/srv/euler/p022_names.txt"),
+            config.path("p022_names.txt")
+        );
+        assert_eq!(
+            "http://localhost:8000/p022_names.txt",
+            config.url("p022_names.txt")
+        );
+    }
+}
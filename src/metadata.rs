@@ -0,0 +1,78 @@
+//! Descriptive information about problems, given to `problem!`.
+
+use serde::Serialize;
+
+const PROBLEM_URL: &str = "https://projecteuler.net/problem=";
+
+/// Title, topic tags, difficulty and crates of a problem.
+///
+/// `problem!` builds it by calling the method of each given key, such as
+/// `problem!(title: "Summation of primes", tags: &["primes"]; "142913828922", solve)`.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct Metadata {
+    pub title: Option<&'static str>,
+    /// Topics of the problem, such as `primes`, `dp`, `combinatorics` or `geometry`.
+    pub tags: &'static [&'static str],
+    /// Difficulty rating on Project Euler in percent.
+    pub difficulty: Option<u32>,
+    /// Crates the solver uses, other than `common`.
+    pub crates: &'static [&'static str],
+}
+
+impl Metadata {
+    pub fn title(self, title: &'static str) -> Metadata {
+        Metadata {
+            title: Some(title),
+            ..self
+        }
+    }
+
+    pub fn tags(self, tags: &'static [&'static str]) -> Metadata {
+        Metadata { tags, ..self }
+    }
+
+    pub fn difficulty(self, difficulty: u32) -> Metadata {
+        Metadata {
+            difficulty: Some(difficulty),
+            ..self
+        }
+    }
+
+    pub fn crates(self, crates: &'static [&'static str]) -> Metadata {
+        Metadata { crates, ..self }
+    }
+
+    /// Returns `true` if the problem has the tag.
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags.contains(&tag)
+    }
+
+    /// Returns `true` if the solver uses the crate.
+    pub fn uses_crate(&self, name: &str) -> bool {
+        self.crates.contains(&name)
+    }
+}
+
+/// Returns the link to the statement of the problem on Project Euler.
+pub fn url(number: u32) -> String {
+    format!("{}{}", PROBLEM_URL, number)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{url, Metadata};
+
+    #[test]
+    fn builder() {
+        let m = Metadata::default()
+            .title("Summation of primes")
+            .tags(&["primes", "sieve"])
+            .difficulty(5)
+            .crates(&["prime"]);
+        assert_eq!(Some("Summation of primes"), m.title);
+        assert!(m.has_tag("primes") && !m.has_tag("dp"));
+        assert!(m.uses_crate("prime") && !m.uses_crate("seq"));
+        assert_eq!(Some(5), m.difficulty);
+        assert_eq!("https://projecteuler.net/problem=10", url(10));
+    }
+}
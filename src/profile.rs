@@ -0,0 +1,215 @@
+//! Timing spans of the phases of solvers.
+//!
+//! A solver times a phase by keeping the guard returned by `span` alive while
+//! the phase runs, such as `let _t = common::span("sieve");`. Spans opened
+//! while another is alive are nested in it, and spans of the same name under
+//! the same parent are merged, so spans may be opened in loops.
+//!
+//! Spans are recorded per thread. Those opened in threads spawned by the solver
+//! are discarded.
+
+use serde::{Deserialize, Serialize};
+use std::{cell::RefCell, collections::BTreeMap, time::Instant};
+
+/// Time spent in a phase of a solver.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct Span {
+    pub name: String,
+    /// Total nanoseconds spent in the span.
+    pub time: u128,
+    /// Number of times the span was entered.
+    #[serde(default)]
+    pub calls: u64,
+    #[serde(default)]
+    pub children: Vec<Span>,
+}
+
+impl Span {
+    fn new(name: &str) -> Span {
+        Span {
+            name: name.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Returns the span and its descendants with their depths, parents first.
+    pub fn walk(&self) -> Vec<(usize, &Span)> {
+        let mut spans = vec![];
+        self.walk_into(0, &mut spans);
+        spans
+    }
+
+    fn walk_into<'a>(&'a self, depth: usize, spans: &mut Vec<(usize, &'a Span)>) {
+        spans.push((depth, self));
+        for child in &self.children {
+            child.walk_into(depth + 1, spans);
+        }
+    }
+
+    /// Divides the times and calls, to average the spans of `n` runs.
+    pub fn divide(&mut self, n: usize) {
+        self.time /= n as u128;
+        self.calls /= n as u64;
+        for child in &mut self.children {
+            child.divide(n);
+        }
+    }
+}
+
+/// Adds `span` to `spans`, merging it with the span of the same name, if any.
+pub fn merge(spans: &mut Vec<Span>, span: Span) {
+    match spans.iter_mut().find(|s| s.name == span.name) {
+        Some(s) => {
+            s.time += span.time;
+            s.calls += span.calls;
+            for child in span.children {
+                merge(&mut s.children, child);
+            }
+        }
+        None => spans.push(span),
+    }
+}
+
+/// Sums the times of the spans by name. Spans nested in a span of the same
+/// name are not counted again.
+pub fn totals(spans: &[Span], totals: &mut BTreeMap<String, u128>) {
+    fn add<'a>(span: &'a Span, open: &mut Vec<&'a str>, totals: &mut BTreeMap<String, u128>) {
+        let is_nested = open.contains(&&span.name[..]);
+        if !is_nested {
+            *totals.entry(span.name.clone()).or_insert(0) += span.time;
+        }
+        open.push(&span.name);
+        for child in &span.children {
+            add(child, open, totals);
+        }
+        let _ = open.pop();
+    }
+
+    let mut open = vec![];
+    for span in spans {
+        add(span, &mut open, totals);
+    }
+}
+
+// Open spans, innermost last, above the top-level spans which are closed.
+thread_local! {
+    static STACK: RefCell<Vec<Span>> = const { RefCell::new(vec![]) };
+}
+
+/// Keeps a span open until dropped.
+#[must_use = "the span is closed when the guard is dropped"]
+#[derive(Debug)]
+pub struct Guard {
+    depth: usize,
+    start: Instant,
+}
+
+impl Drop for Guard {
+    fn drop(&mut self) {
+        let time = self.start.elapsed().as_nanos();
+        STACK.with(|stack| {
+            let mut stack = stack.borrow_mut();
+            // The stack is shallower if the recording was restarted meanwhile.
+            if stack.len() <= self.depth {
+                return;
+            }
+            // Spans opened later but still alive are closed with this one.
+            while stack.len() > self.depth + 1 {
+                let span = stack.pop().unwrap();
+                merge(&mut stack.last_mut().unwrap().children, span);
+            }
+            let mut span = stack.pop().unwrap();
+            span.time = time;
+            span.calls = 1;
+            merge(&mut stack.last_mut().unwrap().children, span);
+        });
+    }
+}
+
+/// Opens the span `name`, which is closed when the returned guard is dropped.
+pub fn span(name: &str) -> Guard {
+    let depth = STACK.with(|stack| {
+        let mut stack = stack.borrow_mut();
+        if stack.is_empty() {
+            stack.push(Span::default());
+        }
+        stack.push(Span::new(name));
+        stack.len() - 1
+    });
+    Guard {
+        depth,
+        start: Instant::now(),
+    }
+}
+
+/// Discards the spans recorded by the current thread.
+pub fn start() {
+    STACK.with(|stack| {
+        let mut stack = stack.borrow_mut();
+        stack.clear();
+        stack.push(Span::default());
+    });
+}
+
+/// Returns the top-level spans recorded by the current thread since `start`.
+pub fn finish() -> Vec<Span> {
+    STACK.with(|stack| {
+        let mut stack = stack.borrow_mut();
+        let root = stack.drain(..).next();
+        root.map(|r| r.children).unwrap_or_default()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{finish, span, start, totals, Span};
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn nested() {
+        start();
+        {
+            let _t = span("sieve");
+            for _ in 0..3 {
+                let _t = span("grow");
+            }
+        }
+        let _t = span("search");
+        drop(_t);
+        let spans = finish();
+
+        let names = spans
+            .iter()
+            .flat_map(Span::walk)
+            .map(|(depth, s)| (depth, &s.name[..], s.calls))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![(0, "sieve", 1), (1, "grow", 3), (0, "search", 1)],
+            names
+        );
+        assert!(spans[0].time >= spans[0].children[0].time);
+        assert!(finish().is_empty());
+    }
+
+    #[test]
+    fn total() {
+        let span = |name: &str, time, children| Span {
+            name: name.to_string(),
+            time,
+            calls: 1,
+            children,
+        };
+        let spans = vec![
+            span(
+                "search",
+                10,
+                vec![span("grow", 3, vec![span("grow", 2, vec![])])],
+            ),
+            span("grow", 4, vec![]),
+        ];
+        let mut sums = BTreeMap::new();
+        totals(&spans, &mut sums);
+        assert_eq!(Some(&7), sums.get("grow"));
+        assert_eq!(Some(&10), sums.get("search"));
+    }
+}
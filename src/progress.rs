@@ -0,0 +1,226 @@
+//! Progress reports of long-running solvers.
+//!
+//! A solver calls `fraction`, `count` or `best` as it goes. The problem binary
+//! shows the progress as a status line on terminals, or writes it to stderr as
+//! JSON events with `--json`, which the `euler` runner reads to find stalling
+//! problems. Reports are discarded if neither is enabled, such as in tests.
+//!
+//! The functions return quickly when nothing is shown, but still lock a mutex,
+//! so they should not be called in the innermost loops.
+
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt,
+    io::{self, prelude::*, IsTerminal},
+    sync::{
+        atomic::{AtomicU8, Ordering},
+        Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+const TERMINAL_INTERVAL: Duration = Duration::from_millis(100);
+const JSON_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Where progress reports are written.
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+pub enum Mode {
+    Off = 0,
+    Terminal = 1,
+    Json = 2,
+}
+
+static MODE: AtomicU8 = AtomicU8::new(Mode::Off as u8);
+static STATE: Mutex<State> = Mutex::new(State::new());
+
+/// Progress of a solver, as reported last.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct Event {
+    /// Nanoseconds since the solver started.
+    pub time: u128,
+    /// Fraction of the work done, from 0 to 1.
+    #[serde(default)]
+    pub fraction: Option<f64>,
+    /// Number of items processed.
+    #[serde(default)]
+    pub count: Option<u64>,
+    /// Best value found so far.
+    #[serde(default)]
+    pub best: Option<String>,
+}
+
+impl fmt::Display for Event {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut items = vec![];
+        if let Some(fraction) = self.fraction {
+            items.push(format!("{:.1}%", fraction * 100.0));
+        }
+        if let Some(count) = self.count {
+            items.push(format!("count {}", count));
+        }
+        if let Some(ref best) = self.best {
+            items.push(format!("best {}", best));
+        }
+        if items.is_empty() {
+            items.push("started".to_string());
+        }
+        write!(f, "{}", items.join(", "))
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct EventLine {
+    progress: Event,
+}
+
+/// Parses a line of stderr written in `Mode::Json`.
+pub fn parse_event(line: &str) -> Option<Event> {
+    if !line.starts_with("{\"progress\":") {
+        return None;
+    }
+    serde_json::from_str::<EventLine>(line)
+        .ok()
+        .map(|l| l.progress)
+}
+
+struct State {
+    start: Option<Instant>,
+    last_shown: Option<Instant>,
+    event: Event,
+    is_line_shown: bool,
+}
+
+impl State {
+    const fn new() -> State {
+        State {
+            start: None,
+            last_shown: None,
+            event: Event {
+                time: 0,
+                fraction: None,
+                count: None,
+                best: None,
+            },
+            is_line_shown: false,
+        }
+    }
+}
+
+fn mode() -> Mode {
+    match MODE.load(Ordering::Relaxed) {
+        1 => Mode::Terminal,
+        2 => Mode::Json,
+        _ => Mode::Off,
+    }
+}
+
+/// Starts reporting the progress in `Mode::Json` if `json` is `true`, or in
+/// `Mode::Terminal` if stderr is a terminal.
+pub fn start(json: bool) {
+    let mode = if json {
+        Mode::Json
+    } else if io::stderr().is_terminal() {
+        Mode::Terminal
+    } else {
+        Mode::Off
+    };
+    let mut state = STATE.lock().unwrap();
+    *state = State::new();
+    state.start = Some(Instant::now());
+    MODE.store(mode as u8, Ordering::Relaxed);
+}
+
+/// Stops reporting the progress, clearing the status line.
+pub fn finish() {
+    let mode = mode();
+    MODE.store(Mode::Off as u8, Ordering::Relaxed);
+    let mut state = STATE.lock().unwrap();
+    if mode == Mode::Terminal && state.is_line_shown {
+        if let Some(mut t) = term::stderr() {
+            let _ = t.carriage_return();
+            let _ = t.delete_line();
+            let _ = t.flush();
+        }
+        state.is_line_shown = false;
+    }
+}
+
+/// Reports that `fraction` (from 0 to 1) of the work is done.
+pub fn fraction(fraction: f64) {
+    update(|e| e.fraction = Some(fraction));
+}
+
+/// Reports that `count` items are processed.
+pub fn count(count: u64) {
+    update(|e| e.count = Some(count));
+}
+
+/// Reports the best value found so far.
+pub fn best<T: fmt::Display>(value: T) {
+    update(|e| e.best = Some(value.to_string()));
+}
+
+fn update<F: FnOnce(&mut Event)>(f: F) {
+    let mode = mode();
+    if mode == Mode::Off {
+        return;
+    }
+
+    let mut state = STATE.lock().unwrap();
+    f(&mut state.event);
+    let now = Instant::now();
+    let interval = match mode {
+        Mode::Json => JSON_INTERVAL,
+        _ => TERMINAL_INTERVAL,
+    };
+    if state.last_shown.is_some_and(|t| now - t < interval) {
+        return;
+    }
+    state.last_shown = Some(now);
+    state.event.time = state.start.map_or(0, |t| (now - t).as_nanos());
+
+    match mode {
+        Mode::Json => {
+            let line = EventLine {
+                progress: state.event.clone(),
+            };
+            if let Ok(s) = serde_json::to_string(&line) {
+                let _ = writeln!(&mut io::stderr(), "{}", s);
+            }
+        }
+        Mode::Terminal => {
+            if let Some(mut t) = term::stderr() {
+                let _ = t.carriage_return();
+                let _ = t.delete_line();
+                let _ = write!(&mut t, "{}", state.event);
+                let _ = t.flush();
+                state.is_line_shown = true;
+            }
+        }
+        Mode::Off => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_event, Event, EventLine};
+
+    #[test]
+    fn event() {
+        let event = Event {
+            time: 1_000,
+            fraction: Some(0.4213),
+            count: Some(12),
+            best: Some("26033".to_string()),
+        };
+        assert_eq!("42.1%, count 12, best 26033", event.to_string());
+        assert_eq!("started", Event::default().to_string());
+
+        let line = serde_json::to_string(&EventLine {
+            progress: event.clone(),
+        })
+        .unwrap();
+        assert_eq!(Some(event), parse_event(&line));
+        assert_eq!(None, parse_event("thread 'main' panicked"));
+    }
+}